@@ -0,0 +1,245 @@
+//! The registry of known answers, `answers.toml`. Each section is named after a file in
+//! `inputs/` and lists the expected answer for each part solved from that file:
+//!
+//! ```toml
+//! [example8-2]
+//! part1 = 6
+//! ```
+//!
+//! The day comes from the section name, so `example8-2` and `input8` both belong to day 8. Only
+//! the little bit of TOML needed for that is understood: whole-line comments, `[section]`
+//! headers, and `partN = value` where the value is an integer or a double-quoted string with
+//! TOML's backslash escapes.
+
+use std::{fs, path::Path};
+
+use crate::{Answer, Part};
+
+/// Where the registry lives, relative to the crate root.
+pub const REGISTRY_PATH: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+	/// file name in `inputs/`, without the `.txt`
+	pub input: String,
+	pub day: u8,
+	pub part: Part,
+	pub answer: Answer,
+}
+
+impl Expected {
+	/// Examples come with the puzzle text, so unlike real inputs they are checked in.
+	pub fn is_example(&self) -> bool {
+		self.input.starts_with("example")
+	}
+
+	pub fn input_path(&self, root: &Path) -> std::path::PathBuf {
+		root.join("inputs").join(format!("{}.txt", self.input))
+	}
+}
+
+/// Works out the day from an input name like `example10-3` or `input7`.
+fn day_of(input: &str) -> Option<u8> {
+	let rest = input
+		.strip_prefix("example")
+		.or_else(|| input.strip_prefix("input"))?;
+	let day = rest.split('-').next()?;
+	day.parse().ok()
+}
+
+fn parse_value(value: &str) -> Option<Answer> {
+	if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+		unescape(text).map(Answer::Text)
+	} else {
+		value.parse().ok().map(Answer::Number)
+	}
+}
+
+fn format_value(answer: &Answer) -> String {
+	match answer {
+		Answer::Number(n) => n.to_string(),
+		Answer::Text(text) => format!("\"{}\"", escape(text)),
+	}
+}
+
+/// Escapes `text` for a TOML basic string, the double-quoted kind.
+fn escape(text: &str) -> String {
+	let mut escaped = String::new();
+	for c in text.chars() {
+		match c {
+			'"' => escaped += "\\\"",
+			'\\' => escaped += "\\\\",
+			'\n' => escaped += "\\n",
+			'\t' => escaped += "\\t",
+			'\r' => escaped += "\\r",
+			c if c.is_control() => escaped += &format!("\\u{:04X}", c as u32),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+/// The text of a TOML basic string, between the quotes. `None` if it has a bare quote or an
+/// escape TOML doesn't have.
+fn unescape(text: &str) -> Option<String> {
+	let mut unescaped = String::new();
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		let c = match c {
+			'"' => return None,
+			'\\' => match chars.next()? {
+				'"' => '"',
+				'\\' => '\\',
+				'n' => '\n',
+				't' => '\t',
+				'r' => '\r',
+				'b' => '\u{8}',
+				'f' => '\u{c}',
+				escape @ ('u' | 'U') => {
+					let digits = if escape == 'u' { 4 } else { 8 };
+					let hex: String = chars.by_ref().take(digits).collect();
+					if hex.len() != digits {
+						return None;
+					}
+					char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+				},
+				_ => return None,
+			},
+			c => c,
+		};
+		unescaped.push(c);
+	}
+	Some(unescaped)
+}
+
+pub fn parse(source: &str) -> Result<Vec<Expected>, String> {
+	let mut expected: Vec<Expected> = Vec::new();
+	let mut section: Option<(String, u8)> = None;
+
+	for (i, line) in source.lines().enumerate() {
+		let line_number = i + 1;
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+
+		if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+			let day = day_of(name).ok_or(format!(
+				"line {line_number}: can't tell which day {name:?} is for"
+			))?;
+			section = Some((name.to_string(), day));
+			continue;
+		}
+
+		let (key, value) = line
+			.split_once('=')
+			.ok_or(format!("line {line_number}: expected `partN = answer`"))?;
+		let (input, day) = section
+			.clone()
+			.ok_or(format!("line {line_number}: answer outside of any section"))?;
+		let part = match key.trim() {
+			"part1" => Part::One,
+			"part2" => Part::Two,
+			key => return Err(format!("line {line_number}: unknown key {key:?}")),
+		};
+		let answer = parse_value(value.trim()).ok_or(format!(
+			"line {line_number}: invalid answer {:?}",
+			value.trim()
+		))?;
+
+		expected.push(Expected {
+			input,
+			day,
+			part,
+			answer,
+		});
+	}
+
+	Ok(expected)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Expected>, String> {
+	let source =
+		fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+	parse(&source).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Where a new key for `section` goes: after its last key, before any blank lines or comments
+/// that lead into the next section. `None` if there's no such section.
+fn section_end(lines: &[String], section: &str) -> Option<usize> {
+	let header = format!("[{section}]");
+	let start = lines.iter().position(|l| l.trim() == header)?;
+	let mut end = lines[start + 1..]
+		.iter()
+		.position(|l| l.trim().starts_with('['))
+		.map_or(lines.len(), |i| start + 1 + i);
+	while end > start + 1
+		&& (lines[end - 1].trim().is_empty() || lines[end - 1].trim().starts_with('#'))
+	{
+		end -= 1;
+	}
+	Some(end)
+}
+
+/// Adds the entries whose input and part the registry at `path` doesn't cover yet, and returns
+/// the ones that were added. Answers for a section the file already has go at the end of that
+/// section, and new sections at the end of the file, each under `comment` to say where they
+/// came from.
+pub fn add(path: &Path, entries: &[Expected], comment: &str) -> Result<Vec<Expected>, String> {
+	let source =
+		fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {e}", path.display()))?;
+	let registry = parse(&source).map_err(|e| format!("{}: {e}", path.display()))?;
+	let mut added: Vec<Expected> = Vec::new();
+	for entry in entries {
+		let known = registry
+			.iter()
+			.chain(&added)
+			.any(|e| e.input == entry.input && e.part == entry.part);
+		if !known {
+			added.push(entry.clone());
+		}
+	}
+	if added.is_empty() {
+		return Ok(added);
+	}
+
+	let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+	let comment = format!("# {comment}");
+	let mut inputs: Vec<&str> = Vec::new();
+	for expected in &added {
+		if !inputs.contains(&expected.input.as_str()) {
+			inputs.push(&expected.input);
+		}
+	}
+	let mut appended = false;
+	for input in inputs {
+		let mut new_lines = vec![comment.clone()];
+		new_lines.extend(
+			added
+				.iter()
+				.filter(|e| e.input == input)
+				.map(|e| format!("part{} = {}", e.part, format_value(&e.answer))),
+		);
+		match section_end(&lines, input) {
+			Some(end) => {
+				lines.splice(end..end, new_lines);
+			},
+			None => {
+				if !appended {
+					if !lines.is_empty() {
+						lines.push(String::new());
+					}
+					lines.push(comment.clone());
+					appended = true;
+				}
+				lines.push(format!("[{input}]"));
+				lines.extend(new_lines.into_iter().skip(1));
+			},
+		}
+	}
+
+	let mut text = lines.join("\n");
+	text.push('\n');
+	fs::write(path, text).map_err(|e| format!("couldn't write {}: {e}", path.display()))?;
+	Ok(added)
+}
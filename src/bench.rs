@@ -0,0 +1,261 @@
+//! Timing for `aoc bench`. Parsing and each part are measured separately, and every run is
+//! appended to a history file so that the next run can tell whether a change made things slower.
+//! A run is only compared with earlier runs of the same day on the same input with the same
+//! parameters, since timing an example against the real input says nothing about the code.
+
+use std::{
+	fmt::Display,
+	fs::{self, OpenOptions},
+	hint::black_box,
+	io::Write,
+	path::Path,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{params::Params, parse::ParseError, Day, Part};
+
+/// Where `aoc bench` keeps its history, relative to the crate root.
+pub const HISTORY_PATH: &str = ".aoc/bench-history.tsv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+	Parse,
+	Part1,
+	Part2,
+}
+
+impl Phase {
+	fn name(&self) -> &'static str {
+		match self {
+			Phase::Parse => "parse",
+			Phase::Part1 => "part1",
+			Phase::Part2 => "part2",
+		}
+	}
+
+	fn from_name(name: &str) -> Option<Phase> {
+		match name {
+			"parse" => Some(Phase::Parse),
+			"part1" => Some(Phase::Part1),
+			"part2" => Some(Phase::Part2),
+			_ => None,
+		}
+	}
+}
+
+impl Display for Phase {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.name())
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+	pub iterations: usize,
+	pub min: Duration,
+	pub median: Duration,
+	pub stddev: Duration,
+}
+
+impl Stats {
+	pub fn from_samples(samples: &[Duration]) -> Stats {
+		assert!(!samples.is_empty(), "no samples to summarize");
+		let mut sorted = samples.to_vec();
+		sorted.sort();
+
+		let mid = sorted.len() / 2;
+		let median = if sorted.len() % 2 == 0 {
+			(sorted[mid - 1] + sorted[mid]) / 2
+		} else {
+			sorted[mid]
+		};
+
+		let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+		let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+		let variance =
+			secs.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / secs.len() as f64;
+
+		Stats {
+			iterations: samples.len(),
+			min: sorted[0],
+			median,
+			stddev: Duration::from_secs_f64(variance.sqrt()),
+		}
+	}
+}
+
+/// Runs `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+	for _ in 0..warmup {
+		black_box(f());
+	}
+
+	let samples: Vec<Duration> = (0..iterations)
+		.map(|_| {
+			let start = Instant::now();
+			black_box(f());
+			start.elapsed()
+		})
+		.collect();
+	Stats::from_samples(&samples)
+}
+
+/// Measures parsing `input` and then each of the day's parts on the parsed input.
+pub fn bench_day(
+	day: &Day,
+	input: &str,
+	params: &Params,
+	warmup: usize,
+	iterations: usize,
+) -> Result<Vec<(Phase, Stats)>, ParseError> {
+	let solution = day.parse_with(input, params)?;
+	let mut results = vec![(
+		Phase::Parse,
+		measure(warmup, iterations, || day.parse_with(input, params)),
+	)];
+
+	for &part in day.parts() {
+		let (phase, stats) = match part {
+			Part::One => (
+				Phase::Part1,
+				measure(warmup, iterations, || solution.part1()),
+			),
+			Part::Two => (
+				Phase::Part2,
+				measure(warmup, iterations, || solution.part2()),
+			),
+		};
+		results.push((phase, stats));
+	}
+	Ok(results)
+}
+
+/// What was measured: which day, on which input, with which parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subject {
+	pub day: u8,
+	/// `input_hash` of the input's contents
+	pub input: String,
+	/// every parameter's value, as `Params` displays them; empty for days without any
+	pub params: String,
+}
+
+impl Subject {
+	pub fn new(day: u8, input: &str, params: &Params) -> Subject {
+		Subject {
+			day,
+			input: input_hash(input),
+			params: params.to_string(),
+		}
+	}
+}
+
+/// A 64-bit FNV-1a hash of `input`, in hex. Unlike `std`'s hasher it's the same on every
+/// toolchain, so the history stays comparable across compiler upgrades.
+pub fn input_hash(input: &str) -> String {
+	let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+		(hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+	});
+	format!("{hash:016x}")
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+	/// seconds since the Unix epoch
+	pub timestamp: u64,
+	pub subject: Subject,
+	pub phase: Phase,
+	pub stats: Stats,
+}
+
+impl Record {
+	fn to_line(&self) -> String {
+		format!(
+			"{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+			self.timestamp,
+			self.subject.day,
+			self.subject.input,
+			self.subject.params,
+			self.phase,
+			self.stats.iterations,
+			self.stats.min.as_nanos(),
+			self.stats.median.as_nanos(),
+			self.stats.stddev.as_nanos()
+		)
+	}
+
+	fn from_line(line: &str) -> Option<Record> {
+		let fields: Vec<&str> = line.split('\t').collect();
+		if fields.len() != 9 {
+			return None;
+		}
+		let nanos = |s: &str| s.parse::<u64>().ok().map(Duration::from_nanos);
+		Some(Record {
+			timestamp: fields[0].parse().ok()?,
+			subject: Subject {
+				day: fields[1].parse().ok()?,
+				input: fields[2].to_string(),
+				params: fields[3].to_string(),
+			},
+			phase: Phase::from_name(fields[4])?,
+			stats: Stats {
+				iterations: fields[5].parse().ok()?,
+				min: nanos(fields[6])?,
+				median: nanos(fields[7])?,
+				stddev: nanos(fields[8])?,
+			},
+		})
+	}
+}
+
+/// Reads every record in the history file. A missing file is an empty history, and lines that
+/// don't parse are skipped, including those from before records said what they measured.
+pub fn load_history(path: &Path) -> Vec<Record> {
+	fs::read_to_string(path)
+		.map(|s| s.lines().filter_map(Record::from_line).collect())
+		.unwrap_or_default()
+}
+
+/// The most recent earlier measurement of the same subject and phase.
+pub fn previous<'a>(history: &'a [Record], subject: &Subject, phase: Phase) -> Option<&'a Record> {
+	history
+		.iter()
+		.rev()
+		.find(|r| r.subject == *subject && r.phase == phase)
+}
+
+pub fn append_history(
+	path: &Path,
+	subject: &Subject,
+	results: &[(Phase, Stats)],
+) -> std::io::Result<()> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+	let timestamp = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0);
+
+	let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+	for &(phase, stats) in results {
+		let record = Record {
+			timestamp,
+			subject: subject.clone(),
+			phase,
+			stats,
+		};
+		writeln!(file, "{}", record.to_line())?;
+	}
+	Ok(())
+}
+
+/// How much slower the median got compared to `before`, as a fraction: 0.25 is 25% slower.
+/// Medians are compared because they shrug off the odd iteration that got interrupted.
+pub fn slowdown(before: &Stats, after: &Stats) -> f64 {
+	if before.median.is_zero() {
+		return 0.0;
+	}
+	after.median.as_secs_f64() / before.median.as_secs_f64() - 1.0
+}
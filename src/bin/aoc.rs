@@ -0,0 +1,420 @@
+use std::{
+	env, fs,
+	io::{self, Read},
+	path::{Path, PathBuf},
+	process::ExitCode,
+};
+
+use aoc2023::{
+	answers::{self, Expected},
+	bench, days,
+	fetch::{self, Client, Outcome, Paths},
+	input,
+	output::Format,
+	params, scaffold,
+	submit::{self, Verdict},
+	Answer, Day, Part,
+};
+
+const USAGE: &str = "\
+usage:
+	aoc run <day> [--part 1|2|both] [--input PATH|-] [--format text|json] [--param NAME=VALUE]...
+	aoc all [--format text|json]
+	aoc list
+	aoc params <day>
+	aoc bench <day> [--input PATH] [--iterations N] [--warmup N] [--threshold PERCENT]
+	          [--param NAME=VALUE]...
+	aoc new <day>
+	aoc fetch <day> [--refresh]
+	aoc submit <day> <1|2> [--input PATH|-] [--answer ANSWER]";
+
+enum Input {
+	Stdin,
+	File(PathBuf),
+}
+
+impl Input {
+	/// Reads the input and normalizes it, warning about anything that had to be fixed.
+	fn read(&self) -> Result<String, String> {
+		let (raw, name) = match self {
+			Input::Stdin => {
+				let mut raw = String::new();
+				io::stdin()
+					.read_to_string(&mut raw)
+					.map_err(|e| format!("couldn't read stdin: {e}"))?;
+				(raw, "stdin".to_string())
+			},
+			Input::File(path) => (
+				fs::read_to_string(path)
+					.map_err(|e| format!("couldn't read {}: {e}", path.display()))?,
+				path.display().to_string(),
+			),
+		};
+		let normalized = input::normalize(&raw);
+		if let Some(summary) = normalized.summary() {
+			eprintln!("warning: {name}: {summary}");
+		}
+		Ok(normalized.text)
+	}
+}
+
+fn parse_day(arg: Option<String>) -> Result<&'static Day, String> {
+	let arg = arg.ok_or("missing day")?;
+	let number: u8 = arg.parse().map_err(|_| format!("invalid day {arg:?}"))?;
+	Day::find(number).ok_or(format!("day {number} is not implemented"))
+}
+
+fn parse_format(arg: Option<String>) -> Result<Format, String> {
+	arg.ok_or("--format needs text or json")?.parse()
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let day = parse_day(args.next())?;
+	let mut parts = day.parts();
+	let mut input = Input::File(day.input_path());
+	let mut format = Format::Text;
+	let mut overrides = Vec::new();
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--part" => {
+				parts = match args.next().as_deref() {
+					Some("both") => day.parts(),
+					Some(p) => match p.parse::<Part>()? {
+						Part::One => &[Part::One],
+						Part::Two if day.has_part2 => &[Part::Two],
+						Part::Two => return Err(format!("day {} has no part 2", day.number)),
+					},
+					None => return Err("--part needs 1, 2 or both".to_string()),
+				}
+			},
+			"--input" => {
+				input = match args.next().as_deref() {
+					Some("-") => Input::Stdin,
+					Some(path) => Input::File(PathBuf::from(path)),
+					None => return Err("--input needs a path, or - for stdin".to_string()),
+				}
+			},
+			"--format" => format = parse_format(args.next())?,
+			"--param" => {
+				let arg = args.next().ok_or("--param needs NAME=VALUE")?;
+				overrides.push(params::parse_override(&arg)?);
+			},
+			_ => return Err(format!("unexpected argument {arg:?}")),
+		}
+	}
+	let params = day.resolve_params(&overrides)?;
+
+	let input = input.read()?;
+	for report in day
+		.solve_with(&input, parts, &params)
+		.map_err(|e| e.report(&input))?
+	{
+		report.print(format);
+	}
+	Ok(())
+}
+
+fn all(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let mut format = Format::Text;
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--format" => format = parse_format(args.next())?,
+			_ => return Err(format!("unexpected argument {arg:?}")),
+		}
+	}
+
+	for day in days::ALL {
+		let path = day.input_path();
+		if !path.exists() {
+			eprintln!("skipping day {}: no {}", day.number, path.display());
+			continue;
+		}
+		let input = Input::File(path).read()?;
+		let reports = day
+			.solve(&input, day.parts())
+			.map_err(|e| format!("day {}: {}", day.number, e.report(&input)))?;
+		for report in reports {
+			report.print(format);
+		}
+	}
+	Ok(())
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, arg: Option<String>) -> Result<T, String> {
+	let arg = arg.ok_or(format!("{flag} needs a number"))?;
+	arg.parse()
+		.map_err(|_| format!("invalid number {arg:?} for {flag}"))
+}
+
+fn bench(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let day = parse_day(args.next())?;
+	let mut input = Input::File(day.input_path());
+	let mut iterations: usize = 10;
+	let mut warmup: usize = 2;
+	let mut threshold: f64 = 10.0;
+	let mut overrides: Vec<(String, i64)> = Vec::new();
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--input" => {
+				input = Input::File(PathBuf::from(args.next().ok_or("--input needs a path")?))
+			},
+			"--iterations" => iterations = parse_number(&arg, args.next())?,
+			"--warmup" => warmup = parse_number(&arg, args.next())?,
+			"--threshold" => threshold = parse_number(&arg, args.next())?,
+			"--param" => {
+				let arg = args.next().ok_or("--param needs NAME=VALUE")?;
+				overrides.push(params::parse_override(&arg)?);
+			},
+			_ => return Err(format!("unexpected argument {arg:?}")),
+		}
+	}
+	if iterations == 0 {
+		return Err("--iterations must be at least 1".to_string());
+	}
+
+	let params = day.resolve_params(&overrides)?;
+
+	let input = input.read()?;
+	let subject = bench::Subject::new(day.number, &input, &params);
+	let history_path = PathBuf::from(bench::HISTORY_PATH);
+	let history = bench::load_history(&history_path);
+	let results =
+		bench::bench_day(day, &input, &params, warmup, iterations).map_err(|e| e.report(&input))?;
+
+	println!(
+		"day {} ({iterations} iterations, {warmup} warmup)",
+		day.number
+	);
+	println!("{:<6} {:>12} {:>12} {:>12}", "", "min", "median", "stddev");
+	let mut regressed = false;
+	for (phase, stats) in &results {
+		print!(
+			"{:<6} {:>12.2?} {:>12.2?} {:>12.2?}",
+			phase.to_string(),
+			stats.min,
+			stats.median,
+			stats.stddev
+		);
+		if let Some(before) = bench::previous(&history, &subject, *phase) {
+			let slowdown = 100.0 * bench::slowdown(&before.stats, stats);
+			print!("  {slowdown:+.1}% vs {:.2?}", before.stats.median);
+			if slowdown > threshold {
+				print!("  REGRESSION");
+				regressed = true;
+			}
+		}
+		println!();
+	}
+
+	bench::append_history(&history_path, &subject, &results)
+		.map_err(|e| format!("couldn't write {}: {e}", history_path.display()))?;
+
+	if regressed {
+		Err(format!("median got more than {threshold}% slower"))
+	} else {
+		Ok(())
+	}
+}
+
+fn new(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let arg = args.next().ok_or("missing day")?;
+	let day: u8 = arg.parse().map_err(|_| format!("invalid day {arg:?}"))?;
+	if let Some(arg) = args.next() {
+		return Err(format!("unexpected argument {arg:?}"));
+	}
+
+	for path in scaffold::new_day(Path::new("."), day)? {
+		eprintln!("wrote {}", path.display());
+	}
+	let example = Paths::new(Path::new("."), day)
+		.examples
+		.join(format!("example{day}.txt"));
+	if !example.exists() {
+		eprintln!(
+			"{} doesn't exist yet; `aoc fetch {day}` will download it",
+			example.display()
+		);
+	}
+	Ok(())
+}
+
+fn fetch(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	// any day can be fetched, including ones that aren't implemented yet
+	let arg = args.next().ok_or("missing day")?;
+	let day: u8 = match arg.parse() {
+		Ok(day @ 1..=25) => day,
+		_ => return Err(format!("invalid day {arg:?}")),
+	};
+	let mut refresh = false;
+	for arg in args {
+		match arg.as_str() {
+			"--refresh" => refresh = true,
+			_ => return Err(format!("unexpected argument {arg:?}")),
+		}
+	}
+
+	let client = Client::from_env()?;
+	let paths = Paths::new(Path::new("."), day);
+	let summary = fetch::fetch_day(&client, &paths, day, refresh)?;
+
+	let report = |outcome: Outcome, path: &Path| match outcome {
+		Outcome::Saved => eprintln!("saved {}", path.display()),
+		Outcome::Cached => eprintln!("already have {}", path.display()),
+	};
+	report(summary.page, &paths.page);
+	report(summary.input, &paths.input);
+	for saved in &summary.examples {
+		report(
+			saved.outcome,
+			&paths.examples.join(format!("{}.txt", saved.name)),
+		);
+	}
+
+	let proposals: Vec<_> = summary
+		.examples
+		.iter()
+		.filter_map(|s| s.proposal(day))
+		.collect();
+	let added = answers::add(
+		Path::new(answers::REGISTRY_PATH),
+		&proposals,
+		"proposed by `aoc fetch` from the puzzle page; check these",
+	)?;
+	for expected in added {
+		eprintln!(
+			"proposed {} part {} = {} in {}; check it against the puzzle",
+			expected.input,
+			expected.part,
+			expected.answer,
+			answers::REGISTRY_PATH
+		);
+	}
+	Ok(())
+}
+
+fn submit(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let day = parse_day(args.next())?;
+	let part: Part = args.next().ok_or("missing part")?.parse()?;
+	if part == Part::Two && !day.has_part2 {
+		return Err(format!("day {} has no part 2", day.number));
+	}
+	let mut input = Input::File(day.input_path());
+	let mut answer: Option<Answer> = None;
+
+	while let Some(arg) = args.next() {
+		match arg.as_str() {
+			"--input" => {
+				input = match args.next().as_deref() {
+					Some("-") => Input::Stdin,
+					Some(path) => Input::File(PathBuf::from(path)),
+					None => return Err("--input needs a path, or - for stdin".to_string()),
+				}
+			},
+			"--answer" => {
+				let arg = args.next().ok_or("--answer needs a value")?;
+				answer = Some(match arg.parse() {
+					Ok(n) => Answer::Number(n),
+					Err(_) => Answer::Text(arg),
+				});
+			},
+			_ => return Err(format!("unexpected argument {arg:?}")),
+		}
+	}
+
+	let answer = match answer {
+		Some(answer) => answer,
+		None => {
+			let input = input.read()?;
+			day.solve(&input, &[part])
+				.map_err(|e| e.report(&input))?
+				.remove(0)
+				.answer
+		},
+	};
+	println!("{answer}");
+
+	let client = Client::from_env()?;
+	let response = submit::submit(
+		&client,
+		Path::new(submit::HISTORY_PATH),
+		day.number,
+		part,
+		&answer,
+	)?;
+	eprintln!("{}", response.message);
+
+	match response.verdict {
+		Verdict::Correct => {
+			let entry = Expected {
+				input: format!("input{}", day.number),
+				day: day.number,
+				part,
+				answer,
+			};
+			answers::add(
+				Path::new(answers::REGISTRY_PATH),
+				&[entry],
+				"accepted by `aoc submit`",
+			)?;
+			Ok(())
+		},
+		verdict => Err(format!("answer was not accepted ({verdict})")),
+	}
+}
+
+fn list() {
+	for day in days::ALL {
+		let parts: Vec<String> = day.parts().iter().map(|p| p.to_string()).collect();
+		println!("day {:2}: part {}", day.number, parts.join(", "));
+	}
+}
+
+fn show_params(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+	let day = parse_day(args.next())?;
+	if let Some(arg) = args.next() {
+		return Err(format!("unexpected argument {arg:?}"));
+	}
+
+	if day.params.is_empty() {
+		println!("day {} has no parameters", day.number);
+	}
+	let width = day.params.iter().map(|p| p.name.len()).max().unwrap_or(0);
+	for param in day.params {
+		println!(
+			"{:width$} = {:<12} {:<14} {}",
+			param.name,
+			param.default,
+			param.range_text(),
+			param.description
+		);
+	}
+	Ok(())
+}
+
+fn main() -> ExitCode {
+	let mut args = env::args().skip(1);
+	let result = match args.next().as_deref() {
+		Some("run") => run(args),
+		Some("all") => all(args),
+		Some("bench") => bench(args),
+		Some("new") => new(args),
+		Some("fetch") => fetch(args),
+		Some("submit") => submit(args),
+		Some("params") => show_params(args),
+		Some("list") => {
+			list();
+			Ok(())
+		},
+		_ => Err(USAGE.to_string()),
+	};
+
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(e) => {
+			eprintln!("{e}");
+			ExitCode::FAILURE
+		},
+	}
+}
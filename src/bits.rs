@@ -0,0 +1,230 @@
+//! Sets of small integers and grids of booleans packed into 64-bit words, so that comparing,
+//! combining and counting them takes a handful of word operations instead of a loop over every
+//! value or cell.
+
+use std::fmt::Debug;
+
+use crate::grid::Grid;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of `usize`s, stored as one bit per value up to the largest. Good for dense sets of
+/// small values; a set holding just `1 << 40` would take a lot of memory.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitSet {
+	// never has trailing zero words, so equal sets have equal words
+	words: Vec<u64>,
+}
+
+impl BitSet {
+	pub fn new() -> BitSet {
+		BitSet::default()
+	}
+
+	/// The words holding the set, with value `i` at bit `i % 64` of word `i / 64`.
+	pub fn words(&self) -> &[u64] {
+		&self.words
+	}
+
+	fn from_words(mut words: Vec<u64>) -> BitSet {
+		while words.last() == Some(&0) {
+			words.pop();
+		}
+		BitSet { words }
+	}
+
+	/// Adds `value`, returning whether it wasn't already there.
+	pub fn insert(&mut self, value: usize) -> bool {
+		let (word, bit) = (value / BITS, 1 << (value % BITS));
+		if word >= self.words.len() {
+			self.words.resize(word + 1, 0);
+		}
+		let added = self.words[word] & bit == 0;
+		self.words[word] |= bit;
+		added
+	}
+
+	/// Removes `value`, returning whether it was there.
+	pub fn remove(&mut self, value: usize) -> bool {
+		if !self.contains(value) {
+			return false;
+		}
+		self.words[value / BITS] &= !(1 << (value % BITS));
+		*self = BitSet::from_words(std::mem::take(&mut self.words));
+		true
+	}
+
+	pub fn contains(&self, value: usize) -> bool {
+		self.words
+			.get(value / BITS)
+			.is_some_and(|w| w & (1 << (value % BITS)) != 0)
+	}
+
+	/// How many values are in the set.
+	pub fn len(&self) -> usize {
+		self.words.iter().map(|w| w.count_ones() as usize).sum()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.words.is_empty()
+	}
+
+	/// Combines the words of both sets with `op`, treating missing words as empty.
+	fn combine(&self, other: &BitSet, op: impl Fn(u64, u64) -> u64) -> BitSet {
+		let len = self.words.len().max(other.words.len());
+		let word = |words: &[u64], i: usize| words.get(i).copied().unwrap_or(0);
+		BitSet::from_words(
+			(0..len)
+				.map(|i| op(word(&self.words, i), word(&other.words, i)))
+				.collect(),
+		)
+	}
+
+	pub fn union(&self, other: &BitSet) -> BitSet {
+		self.combine(other, |a, b| a | b)
+	}
+
+	pub fn intersection(&self, other: &BitSet) -> BitSet {
+		self.combine(other, |a, b| a & b)
+	}
+
+	pub fn difference(&self, other: &BitSet) -> BitSet {
+		self.combine(other, |a, b| a & !b)
+	}
+
+	/// The values in exactly one of the sets.
+	pub fn xor(&self, other: &BitSet) -> BitSet {
+		self.combine(other, |a, b| a ^ b)
+	}
+
+	/// The values in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+		self.words.iter().enumerate().flat_map(|(i, &word)| {
+			let mut word = word;
+			std::iter::from_fn(move || {
+				if word == 0 {
+					return None;
+				}
+				let bit = word.trailing_zeros() as usize;
+				word &= word - 1;
+				Some(i * BITS + bit)
+			})
+		})
+	}
+}
+
+impl FromIterator<usize> for BitSet {
+	fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+		let mut set = BitSet::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl Extend<usize> for BitSet {
+	fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
+
+impl Debug for BitSet {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+/// A grid of booleans, each row a `BitSet` of the `x`s that are set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+	width: usize,
+	height: usize,
+	rows: Vec<BitSet>,
+}
+
+impl BitGrid {
+	/// A grid with nothing set.
+	pub fn new(width: usize, height: usize) -> BitGrid {
+		BitGrid {
+			width,
+			height,
+			rows: vec![BitSet::new(); height],
+		}
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn get(&self, x: usize, y: usize) -> bool {
+		self.rows[y].contains(x)
+	}
+
+	pub fn set(&mut self, x: usize, y: usize, value: bool) {
+		assert!(
+			x < self.width && y < self.height,
+			"({x}, {y}) is outside a {}x{} grid",
+			self.width,
+			self.height
+		);
+		if value {
+			self.rows[y].insert(x);
+		} else {
+			self.rows[y].remove(x);
+		}
+	}
+
+	pub fn row(&self, y: usize) -> &BitSet {
+		&self.rows[y]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &BitSet> + '_ {
+		self.rows.iter()
+	}
+
+	/// The `y`s that are set in column `x`.
+	pub fn column(&self, x: usize) -> BitSet {
+		(0..self.height).filter(|&y| self.get(x, y)).collect()
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = BitSet> + '_ {
+		(0..self.width).map(|x| self.column(x))
+	}
+
+	/// How many cells are set.
+	pub fn count_ones(&self) -> usize {
+		self.rows.iter().map(BitSet::len).sum()
+	}
+
+	/// The positions of the cells that are set, in reading order.
+	pub fn ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+		self.rows
+			.iter()
+			.enumerate()
+			.flat_map(|(y, row)| row.iter().map(move |x| (x, y)))
+	}
+
+	/// The grid flipped over its main diagonal, so rows become columns.
+	pub fn transpose(&self) -> BitGrid {
+		let mut transposed = BitGrid::new(self.height, self.width);
+		for (x, y) in self.ones() {
+			transposed.rows[x].insert(y);
+		}
+		transposed
+	}
+}
+
+impl From<&Grid<bool>> for BitGrid {
+	fn from(grid: &Grid<bool>) -> Self {
+		let mut bits = BitGrid::new(grid.width(), grid.height());
+		for ((x, y), _) in grid.iter().filter(|&(_, &set)| set) {
+			bits.rows[y].insert(x);
+		}
+		bits
+	}
+}
@@ -0,0 +1,194 @@
+//! Integers that refuse to overflow quietly. Plain arithmetic panics on overflow in debug
+//! builds but wraps in release ones, which turns a too-small type into a wrong answer
+//! instead of an error. `Checked` does the same arithmetic with the checked methods and, if
+//! anything overflows, panics naming the operation, its inputs, the type, and the line of the
+//! solver that did it.
+//!
+//! Solvers use it for the arithmetic that depends on how big the input's numbers are, and keep
+//! plain integers for indices and counters that can't get anywhere near the limits.
+
+use std::{
+	fmt::{Debug, Display},
+	iter::{Product, Sum},
+	ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign},
+};
+
+use crate::Answer;
+
+/// The integer types `Checked` works with.
+pub trait Integer: Copy + Display {
+	const ZERO: Self;
+	const ONE: Self;
+
+	fn checked_add(self, rhs: Self) -> Option<Self>;
+	fn checked_sub(self, rhs: Self) -> Option<Self>;
+	fn checked_mul(self, rhs: Self) -> Option<Self>;
+	fn checked_div(self, rhs: Self) -> Option<Self>;
+	fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+	($($t:ty),*) => {
+		$(
+			impl Integer for $t {
+				const ZERO: Self = 0;
+				const ONE: Self = 1;
+
+				fn checked_add(self, rhs: Self) -> Option<Self> {
+					<$t>::checked_add(self, rhs)
+				}
+
+				fn checked_sub(self, rhs: Self) -> Option<Self> {
+					<$t>::checked_sub(self, rhs)
+				}
+
+				fn checked_mul(self, rhs: Self) -> Option<Self> {
+					<$t>::checked_mul(self, rhs)
+				}
+
+				fn checked_div(self, rhs: Self) -> Option<Self> {
+					<$t>::checked_div(self, rhs)
+				}
+
+				fn checked_rem(self, rhs: Self) -> Option<Self> {
+					<$t>::checked_rem(self, rhs)
+				}
+			}
+		)*
+	};
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// An integer whose arithmetic panics with the operation and its inputs when the result
+/// doesn't fit, in every build profile.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked<T>(pub T);
+
+impl<T: Integer> Checked<T> {
+	pub fn get(self) -> T {
+		self.0
+	}
+
+	#[track_caller]
+	fn apply(self, op: &str, rhs: T, f: fn(T, T) -> Option<T>) -> Checked<T> {
+		match f(self.0, rhs) {
+			Some(result) => Checked(result),
+			None => panic!(
+				"overflow: {} {op} {rhs} doesn't fit in {}",
+				self.0,
+				std::any::type_name::<T>()
+			),
+		}
+	}
+}
+
+impl<T> From<T> for Checked<T> {
+	fn from(value: T) -> Self {
+		Checked(value)
+	}
+}
+
+impl<T: Into<Answer>> From<Checked<T>> for Answer {
+	fn from(value: Checked<T>) -> Self {
+		value.0.into()
+	}
+}
+
+impl<T: Display> Display for Checked<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<T: Debug> Debug for Checked<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+macro_rules! operator {
+	($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:literal, $checked:ident) => {
+		impl<T: Integer> $trait for Checked<T> {
+			type Output = Checked<T>;
+
+			#[track_caller]
+			fn $method(self, rhs: Checked<T>) -> Checked<T> {
+				self.apply($op, rhs.0, T::$checked)
+			}
+		}
+
+		impl<T: Integer> $trait<T> for Checked<T> {
+			type Output = Checked<T>;
+
+			#[track_caller]
+			fn $method(self, rhs: T) -> Checked<T> {
+				self.apply($op, rhs, T::$checked)
+			}
+		}
+
+		impl<T: Integer> $assign_trait for Checked<T> {
+			#[track_caller]
+			fn $assign_method(&mut self, rhs: Checked<T>) {
+				*self = self.apply($op, rhs.0, T::$checked);
+			}
+		}
+
+		impl<T: Integer> $assign_trait<T> for Checked<T> {
+			#[track_caller]
+			fn $assign_method(&mut self, rhs: T) {
+				*self = self.apply($op, rhs, T::$checked);
+			}
+		}
+	};
+	($trait:ident, $method:ident, $op:literal, $checked:ident) => {
+		impl<T: Integer> $trait for Checked<T> {
+			type Output = Checked<T>;
+
+			#[track_caller]
+			fn $method(self, rhs: Checked<T>) -> Checked<T> {
+				self.apply($op, rhs.0, T::$checked)
+			}
+		}
+
+		impl<T: Integer> $trait<T> for Checked<T> {
+			type Output = Checked<T>;
+
+			#[track_caller]
+			fn $method(self, rhs: T) -> Checked<T> {
+				self.apply($op, rhs, T::$checked)
+			}
+		}
+	};
+}
+
+operator!(Add, add, AddAssign, add_assign, "+", checked_add);
+operator!(Sub, sub, SubAssign, sub_assign, "-", checked_sub);
+operator!(Mul, mul, MulAssign, mul_assign, "*", checked_mul);
+// dividing by zero is reported the same way as an overflow
+operator!(Div, div, "/", checked_div);
+operator!(Rem, rem, "%", checked_rem);
+
+// `Iterator::sum` and `product` don't pass their caller's location on, so an overflow in
+// them is reported here. Solvers that want their own line reported fold with `+` or `*`.
+impl<T: Integer> Sum for Checked<T> {
+	#[track_caller]
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		let mut total = Checked(T::ZERO);
+		for value in iter {
+			total += value;
+		}
+		total
+	}
+}
+
+impl<T: Integer> Product for Checked<T> {
+	#[track_caller]
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		let mut total = Checked(T::ONE);
+		for value in iter {
+			total *= value;
+		}
+		total
+	}
+}
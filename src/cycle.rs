@@ -0,0 +1,95 @@
+//! Finding where a repeated step starts going round in circles, so the state after a huge
+//! number of steps can be worked out without taking them all. Both searches assume the states
+//! eventually repeat, which is the case whenever there are finitely many of them; otherwise
+//! they never return.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating: the state after `start` steps comes back
+/// every `length` steps from then on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+	pub start: usize,
+	pub length: usize,
+}
+
+impl Period {
+	/// The smallest number of steps that ends up in the same state as `steps` does.
+	pub fn reduce(&self, steps: usize) -> usize {
+		if steps < self.start {
+			steps
+		} else {
+			self.start + (steps - self.start) % self.length
+		}
+	}
+}
+
+/// A period together with every state up to the end of its first repetition.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+	pub period: Period,
+	/// the states after 0, 1, ... `start + length - 1` steps
+	pub states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+	/// The state after `steps` steps, however large that is.
+	pub fn state_after(&self, steps: usize) -> &S {
+		&self.states[self.period.reduce(steps)]
+	}
+}
+
+/// Steps from `initial` until a state comes round again, remembering each one. Fast, but keeps
+/// every state before the repeat in memory.
+pub fn find<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+	let mut seen: HashMap<S, usize> = HashMap::new();
+	let mut states = Vec::new();
+	let mut state = initial;
+	loop {
+		if let Some(&start) = seen.get(&state) {
+			let length = states.len() - start;
+			return Cycle {
+				period: Period { start, length },
+				states,
+			};
+		}
+		seen.insert(state.clone(), states.len());
+		let next = step(&state);
+		states.push(state);
+		state = next;
+	}
+}
+
+/// Brent's algorithm: finds the same period as `find` while only holding two states at a time,
+/// at the cost of taking a few more steps.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Period {
+	// find the length by moving the tortoise up to the hare at each power of two
+	let mut power = 1;
+	let mut length = 1;
+	let mut tortoise = initial.clone();
+	let mut hare = step(&initial);
+	while tortoise != hare {
+		if power == length {
+			tortoise = hare.clone();
+			power *= 2;
+			length = 0;
+		}
+		hare = step(&hare);
+		length += 1;
+	}
+
+	// with the hare `length` steps ahead, they meet where the cycle starts
+	let mut tortoise = initial.clone();
+	let mut hare = initial;
+	for _ in 0..length {
+		hare = step(&hare);
+	}
+	let mut start = 0;
+	while tortoise != hare {
+		tortoise = step(&tortoise);
+		hare = step(&hare);
+		start += 1;
+	}
+
+	Period { start, length }
+}
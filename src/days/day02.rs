@@ -0,0 +1,92 @@
+use crate::{
+	checked::Checked,
+	params::{Param, Params},
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Debug)]
+struct Game {
+	id: u32,
+	max_red: u32,
+	max_green: u32,
+	max_blue: u32,
+}
+
+impl Game {
+	fn parse(input: &str, line: &str) -> Result<Game, ParseError> {
+		let (game, reveals) = parse::split_once(input, line, ": ")?;
+		let id: u32 = parse::number(input, parse::strip_prefix(input, game, "Game ")?)?;
+
+		let mut max_red: u32 = 0;
+		let mut max_green: u32 = 0;
+		let mut max_blue: u32 = 0;
+
+		parse::list(reveals, "; ", |reveal| {
+			parse::list(reveal, ", ", |num_and_color| {
+				let mut cursor = parse::Cursor::new(input, num_and_color);
+				let num: u32 = cursor.number()?;
+				cursor.expect(" ")?;
+
+				match cursor.rest() {
+					"red" => max_red = max_red.max(num),
+					"green" => max_green = max_green.max(num),
+					"blue" => max_blue = max_blue.max(num),
+					color => return Err(ParseError::at(input, color, "red, green or blue")),
+				}
+				Ok(())
+			})
+		})?;
+
+		Ok(Game {
+			id,
+			max_red,
+			max_green,
+			max_blue,
+		})
+	}
+}
+
+pub struct Day02 {
+	games: Vec<Game>,
+	params: Params,
+}
+
+impl Solution for Day02 {
+	const PARAMS: &'static [Param] = &[
+		Param::new("red", 12, "red cubes in the bag in part 1").range(0, u32::MAX as i64),
+		Param::new("green", 13, "green cubes in the bag in part 1").range(0, u32::MAX as i64),
+		Param::new("blue", 14, "blue cubes in the bag in part 1").range(0, u32::MAX as i64),
+	];
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day02 {
+			games: input
+				.lines()
+				.map(|l| Game::parse(input, l))
+				.collect::<Result<_, _>>()?,
+			params: Params::defaults(Self::PARAMS),
+		})
+	}
+
+	fn configure(&mut self, params: &Params) {
+		self.params = params.clone();
+	}
+
+	fn part1(&self) -> Answer {
+		let [red, green, blue]: [u32; 3] = ["red", "green", "blue"].map(|c| self.params.get(c));
+		let sum_game_ids = self
+			.games
+			.iter()
+			.filter(|g| g.max_red <= red && g.max_green <= green && g.max_blue <= blue)
+			.fold(Checked(0u32), |sum, g| sum + g.id);
+		sum_game_ids.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let sum_powers = self.games.iter().fold(Checked(0u32), |sum, g| {
+			sum + Checked(g.max_red) * g.max_green * g.max_blue
+		});
+		Some(sum_powers.into())
+	}
+}
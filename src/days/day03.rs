@@ -0,0 +1,135 @@
+use crate::{grid::Grid, parse::ParseError, Answer, Solution};
+
+fn has_adjacent_symbols(grid: &Grid<u8>, y: usize, x_range: (usize, usize)) -> bool {
+	let y = y as isize;
+	let x_range = (x_range.0 as isize, x_range.1 as isize);
+
+	for x in (x_range.0 - 1)..(x_range.1 + 1) {
+		if grid.get(x, y - 1).is_some_and(|&c| c != b'.')
+			|| grid.get(x, y + 1).is_some_and(|&c| c != b'.')
+		{
+			return true;
+		}
+	}
+
+	grid.get(x_range.0 - 1, y).is_some_and(|&c| c != b'.')
+		|| grid.get(x_range.1, y).is_some_and(|&c| c != b'.')
+}
+
+fn parse_number_around(grid: &Grid<u8>, y: isize, x: isize) -> u64 {
+	let mut min_x = x;
+	let mut max_x = x;
+	while grid.get(min_x - 1, y).is_some_and(u8::is_ascii_digit) {
+		min_x -= 1;
+	}
+	while grid.get(max_x + 1, y).is_some_and(u8::is_ascii_digit) {
+		max_x += 1;
+	}
+
+	let slice = &grid.row(y as usize)[min_x as usize..(max_x + 1) as usize];
+	std::str::from_utf8(slice).unwrap().parse().unwrap()
+}
+
+fn product_adjacent_numbers(grid: &Grid<u8>, y: isize, x: isize) -> u64 {
+	let mut product: u64 = 1;
+	let mut count_nums = 0;
+
+	if grid.get(x, y - 1).is_some_and(u8::is_ascii_digit) {
+		count_nums += 1;
+		product *= parse_number_around(grid, y - 1, x);
+	} else {
+		if grid.get(x - 1, y - 1).is_some_and(u8::is_ascii_digit) {
+			count_nums += 1;
+			product *= parse_number_around(grid, y - 1, x - 1);
+		}
+		if grid.get(x + 1, y - 1).is_some_and(u8::is_ascii_digit) {
+			count_nums += 1;
+			product *= parse_number_around(grid, y - 1, x + 1);
+		}
+	}
+
+	if grid.get(x, y + 1).is_some_and(u8::is_ascii_digit) {
+		count_nums += 1;
+		product *= parse_number_around(grid, y + 1, x);
+	} else {
+		if grid.get(x - 1, y + 1).is_some_and(u8::is_ascii_digit) {
+			count_nums += 1;
+			product *= parse_number_around(grid, y + 1, x - 1);
+		}
+		if grid.get(x + 1, y + 1).is_some_and(u8::is_ascii_digit) {
+			count_nums += 1;
+			product *= parse_number_around(grid, y + 1, x + 1);
+		}
+	}
+
+	if grid.get(x - 1, y).is_some_and(u8::is_ascii_digit) {
+		count_nums += 1;
+		product *= parse_number_around(grid, y, x - 1);
+	}
+	if grid.get(x + 1, y).is_some_and(u8::is_ascii_digit) {
+		count_nums += 1;
+		product *= parse_number_around(grid, y, x + 1);
+	}
+
+	if count_nums == 2 {
+		product
+	} else {
+		0
+	}
+}
+
+pub struct Day03 {
+	lines: Grid<u8>,
+}
+
+impl Solution for Day03 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		// symbols can be any punctuation, so only the schematic being ASCII is checked
+		let lines = Grid::parse_with(input, input.lines(), "an ASCII character", |c| {
+			c.is_ascii().then_some(c as u8)
+		})?;
+		Ok(Day03 { lines })
+	}
+
+	fn part1(&self) -> Answer {
+		let lines = &self.lines;
+		let mut sum = 0u64;
+
+		for y in 0..lines.height() {
+			let line = lines.row(y);
+			let mut x = 0;
+			while x < line.len() {
+				if line[x].is_ascii_digit() {
+					let slice_start = x;
+					while x < line.len() && line[x].is_ascii_digit() {
+						x += 1;
+					}
+
+					if has_adjacent_symbols(lines, y, (slice_start, x)) {
+						let slice = &line[slice_start..x];
+						let part_num: u64 = std::str::from_utf8(slice).unwrap().parse().unwrap();
+						sum += part_num;
+					}
+
+					continue;
+				}
+				x += 1;
+			}
+		}
+
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let lines = &self.lines;
+		let mut sum = 0u64;
+
+		for ((x, y), &c) in lines.iter() {
+			if c == b'*' {
+				sum += product_adjacent_numbers(lines, y as isize, x as isize);
+			}
+		}
+
+		Some(sum.into())
+	}
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::{
+	bits::BitSet,
+	checked::Checked,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Debug)]
+struct Card {
+	winning: BitSet,
+	have: Vec<u32>,
+}
+
+impl Card {
+	fn parse(input: &str, line: &str) -> Result<Card, ParseError> {
+		let (_, numbers) = parse::split_once(input, line, ":")?;
+		let (winning, have) = parse::split_once(input, numbers, "|")?;
+		let winning: Vec<usize> = parse::numbers(input, winning)?;
+		let have: Vec<u32> = parse::numbers(input, have)?;
+		Ok(Card {
+			winning: winning.into_iter().collect(),
+			have,
+		})
+	}
+
+	fn matches(&self) -> u32 {
+		self.have
+			.iter()
+			.filter(|&&n| self.winning.contains(n as usize))
+			.count() as u32
+	}
+}
+
+pub struct Day04 {
+	cards: Vec<Card>,
+}
+
+impl Solution for Day04 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day04 {
+			cards: input
+				.lines()
+				.map(|l| Card::parse(input, l))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		// a card's value doubles with every match, so it's the first thing to overflow
+		let mut sum = Checked(0u32);
+
+		for card in &self.cards {
+			let mut value = Checked(0u32);
+			for _ in 0..card.matches() {
+				value = if value == Checked(0) {
+					Checked(1)
+				} else {
+					value * 2
+				};
+			}
+			sum += value;
+		}
+
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let mut cards = Checked(0u32);
+		let mut copies: HashMap<u32, Checked<u32>> = HashMap::new();
+		for (card, c) in (1..).zip(&self.cards) {
+			let this_card_copies = *copies.entry(card).or_insert(Checked(1));
+
+			for i in 0..c.matches() {
+				let copying_card = card + i + 1;
+				if let Some(update_copies) = copies.get_mut(&copying_card) {
+					*update_copies += this_card_copies;
+				} else {
+					copies.insert(copying_card, this_card_copies + 1);
+				}
+			}
+
+			cards += this_card_copies;
+		}
+
+		Some(cards.into())
+	}
+}
@@ -0,0 +1,142 @@
+use crate::{
+	checked::Checked,
+	interval::{Interval, IntervalSet},
+	iter::SliceExt,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+/// Converts `numbers`, read from `text`, to the `i64`s intervals are made of. The puzzle's
+/// numbers are never negative, so they're read as `u64`s first.
+fn fit_in_i64(input: &str, text: &str, numbers: Vec<u64>) -> Result<Vec<i64>, ParseError> {
+	numbers
+		.into_iter()
+		.map(i64::try_from)
+		.collect::<Result<_, _>>()
+		.map_err(|_| ParseError::at(input, text, "numbers that fit in i64"))
+}
+
+#[derive(Debug)]
+struct Mapping {
+	source: Interval,
+	/// how far values in `source` move
+	shift: i64,
+}
+
+#[derive(Debug)]
+struct Mapper {
+	mappings: Vec<Mapping>,
+}
+
+impl Mapper {
+	/// Builds the map in `section`, which has to be headed `header`.
+	pub fn build(input: &str, section: &str, header: &str) -> Result<Mapper, ParseError> {
+		let mut map = Mapper {
+			mappings: Vec::new(),
+		};
+		for l in parse::section_body(input, section, header)?.lines() {
+			let numbers = fit_in_i64(input, l, parse::numbers(input, l)?)?;
+			let &[dst_start, src_start, len] = numbers.as_slice() else {
+				return Err(ParseError::at(input, l, "three numbers"));
+			};
+			map.mappings.push(Mapping {
+				source: Interval::from_len(src_start, len),
+				shift: (Checked(dst_start) - src_start).get(),
+			});
+		}
+		Ok(map)
+	}
+
+	pub fn map(&self, input: i64) -> i64 {
+		for map in &self.mappings {
+			if map.source.contains(input) {
+				return (Checked(input) + map.shift).get();
+			}
+		}
+		input
+	}
+
+	/// Maps every value in `input` at once. Values no mapping covers stay where they are.
+	pub fn map_set(&self, input: &IntervalSet) -> IntervalSet {
+		let mut unmapped = input.clone();
+		let mut mapped = IntervalSet::new();
+		for map in &self.mappings {
+			let moved = unmapped.intersect_interval(map.source);
+			unmapped = unmapped.difference(&moved);
+			mapped = mapped.union(&moved.shift(map.shift));
+		}
+		mapped.union(&unmapped)
+	}
+
+	pub fn map_many(mappers: &[Mapper], input: i64) -> i64 {
+		let mut current = input;
+		for m in mappers {
+			current = m.map(current);
+		}
+		current
+	}
+}
+
+pub struct Day05 {
+	seeds: Vec<i64>,
+	maps: [Mapper; 7],
+}
+
+impl Solution for Day05 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut sections = parse::sections(input);
+
+		let seeds_line = sections
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a list of seeds"))?;
+		let seeds_text = parse::strip_prefix(input, seeds_line, "seeds: ")?;
+		let seeds = fit_in_i64(input, seeds_text, parse::numbers(input, seeds_text)?)?;
+
+		let mut map = |header: &str| match sections.next() {
+			Some(section) => Mapper::build(input, section, header),
+			None => Err(ParseError::end(input, format!("{header:?}"))),
+		};
+		let seed_to_soil = map("seed-to-soil map:")?;
+		let soil_to_fertilizer = map("soil-to-fertilizer map:")?;
+		let fertilizer_to_water = map("fertilizer-to-water map:")?;
+		let water_to_light = map("water-to-light map:")?;
+		let light_to_temperature = map("light-to-temperature map:")?;
+		let temperature_to_humidity = map("temperature-to-humidity map:")?;
+		let humidity_to_location = map("humidity-to-location map:")?;
+
+		Ok(Day05 {
+			seeds,
+			maps: [
+				seed_to_soil,
+				soil_to_fertilizer,
+				fertilizer_to_water,
+				water_to_light,
+				light_to_temperature,
+				temperature_to_humidity,
+				humidity_to_location,
+			],
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let lowest_location = self
+			.seeds
+			.iter()
+			.map(|s| Mapper::map_many(&self.maps, *s))
+			.min()
+			.unwrap();
+		lowest_location.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let mut values: IntervalSet = SliceExt::array_chunks::<2>(&self.seeds[..])
+			.map(|&[start, len]| Interval::from_len(start, len))
+			.collect();
+
+		for mapper in &self.maps {
+			values = mapper.map_set(&values);
+		}
+
+		Some(values.min().unwrap().into())
+	}
+}
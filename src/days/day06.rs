@@ -0,0 +1,83 @@
+use crate::{
+	checked::Checked,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+fn calculate_distance(total_time: u64, time_held: u64) -> u64 {
+	(Checked(time_held) * (Checked(total_time) - time_held)).get()
+}
+
+/// Counts the ways to hold the button so that the boat beats the record distance.
+fn count_ways_to_win(time: u64, distance: u64) -> usize {
+	let mut range = (time / 2)..=(time / 2);
+	while *range.start() > 0 && calculate_distance(time, *range.start() - 1) > distance {
+		range = (range.start() - 1)..=(*range.end());
+	}
+	while *range.end() < time && calculate_distance(time, *range.end() + 1) > distance {
+		range = (*range.start())..=(range.end() + 1);
+	}
+	range.count()
+}
+
+/// Part 2 reads each line as one number with the spaces removed, e.g. `7  15   30` as 71530.
+fn join_digits(numbers: &[u64]) -> u64 {
+	numbers
+		.iter()
+		.fold(String::new(), |mut acc, part| {
+			acc += &part.to_string();
+			acc
+		})
+		.parse()
+		.unwrap()
+}
+
+pub struct Day06 {
+	times: Vec<u64>,
+	distances: Vec<u64>,
+}
+
+impl Solution for Day06 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut iter = input.lines();
+
+		let times_line = iter
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a line of times"))?;
+		let times: Vec<u64> =
+			parse::numbers(input, parse::strip_prefix(input, times_line, "Time:")?)?;
+
+		let distances_line = iter
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a line of distances"))?;
+		let distances: Vec<u64> = parse::numbers(
+			input,
+			parse::strip_prefix(input, distances_line, "Distance:")?,
+		)?;
+
+		if distances.len() != times.len() {
+			return Err(ParseError::at(
+				input,
+				distances_line,
+				format!("{} distances, one for each race", times.len()),
+			));
+		}
+		Ok(Day06 { times, distances })
+	}
+
+	fn part1(&self) -> Answer {
+		let mut product = Checked(1u64);
+
+		for (&time, &distance) in self.times.iter().zip(self.distances.iter()) {
+			product *= count_ways_to_win(time, distance) as u64;
+		}
+
+		product.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let time = join_digits(&self.times);
+		let distance = join_digits(&self.distances);
+		Some(count_ways_to_win(time, distance).into())
+	}
+}
@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+use crate::{
+	checked::Checked,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+	HighCard,
+	OnePair,
+	TwoPair,
+	ThreeOfKind,
+	FullHouse,
+	FourOfKind,
+	FiveOfKind,
+}
+
+/// Card values. Jacks are 11, or 1 once they've been turned into jokers for part 2.
+const JACK: u8 = 11;
+const JOKER: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Hand {
+	cards: [u8; 5],
+}
+
+impl Hand {
+	pub fn parse(input: &str, text: &str) -> Result<Hand, ParseError> {
+		let values = parse::chars(input, text, "a card", |c| match c {
+			'2'..='9' => Some(c as u8 - b'0'),
+			'T' => Some(10),
+			'J' => Some(JACK),
+			'Q' => Some(12),
+			'K' => Some(13),
+			'A' => Some(14),
+			_ => None,
+		})?;
+		let cards = values
+			.try_into()
+			.map_err(|_| ParseError::at(input, text, "five cards"))?;
+		Ok(Hand { cards })
+	}
+
+	fn count(&self, card: u8) -> u8 {
+		let mut count: u8 = 0;
+		for c in self.cards {
+			if c == card {
+				count += 1;
+			}
+		}
+		count
+	}
+
+	pub fn classify(&self) -> HandType {
+		if self.count(self.cards[0]) == 5 {
+			HandType::FiveOfKind
+		} else if self.count(self.cards[0]) == 4 || self.count(self.cards[1]) == 4 {
+			HandType::FourOfKind
+		} else if self.cards.iter().any(|&c| self.count(c) == 3) {
+			if self.cards.iter().any(|&c| self.count(c) == 2) {
+				HandType::FullHouse
+			} else {
+				HandType::ThreeOfKind
+			}
+		} else if let Some(&paired) = self.cards.iter().find(|&&c| self.count(c) == 2) {
+			if self
+				.cards
+				.iter()
+				.any(|&c| c != paired && self.count(c) == 2)
+			{
+				HandType::TwoPair
+			} else {
+				HandType::OnePair
+			}
+		} else {
+			HandType::HighCard
+		}
+	}
+
+	/// The same hand with every jack turned into a joker, which is worth the least on its own.
+	pub fn with_jokers(&self) -> Hand {
+		let mut new_hand = *self;
+		for c in &mut new_hand.cards {
+			if *c == JACK {
+				*c = JOKER;
+			}
+		}
+		new_hand
+	}
+
+	/// Replaces jokers with whichever card makes the strongest hand. Hands without jokers are
+	/// returned unchanged.
+	pub fn replace_jokers(&self) -> Hand {
+		let mut new_hand = *self;
+		let mut most_common_card = 0u8;
+		let mut most_occurrences = 0u8;
+
+		// if all jokers, return all aces
+		if self.count(JOKER) == 5 {
+			return Hand { cards: [14; 5] };
+		}
+
+		for c in self.cards {
+			if c == JOKER {
+				// skip jokers
+				continue;
+			}
+
+			let count = self.count(c);
+			if count > most_occurrences || (count == most_occurrences && c > most_common_card) {
+				most_common_card = c;
+				most_occurrences = count;
+			}
+		}
+
+		for c in &mut new_hand.cards {
+			// replace jokers with the most common card
+			if *c == JOKER {
+				*c = most_common_card;
+			}
+		}
+
+		new_hand
+	}
+}
+
+impl PartialOrd for Hand {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Hand {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		let self_type = self.replace_jokers().classify();
+		let other_type = other.replace_jokers().classify();
+		if self_type != other_type {
+			return self_type.cmp(&other_type);
+		} else {
+			for (&sc, &oc) in self.cards.iter().zip(other.cards.iter()) {
+				if sc > oc {
+					return std::cmp::Ordering::Greater;
+				} else if sc < oc {
+					return std::cmp::Ordering::Less;
+				}
+			}
+		}
+		std::cmp::Ordering::Equal
+	}
+}
+
+impl Display for Hand {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for c in self.cards {
+			let card = match c {
+				JOKER => "J",
+				2 => "2",
+				3 => "3",
+				4 => "4",
+				5 => "5",
+				6 => "6",
+				7 => "7",
+				8 => "8",
+				9 => "9",
+				10 => "T",
+				JACK => "J",
+				12 => "Q",
+				13 => "K",
+				14 => "A",
+				_ => "",
+			};
+			write!(f, "{card}")?;
+		}
+		Ok(())
+	}
+}
+
+fn total_winnings(mut hands_and_bids: Vec<(Hand, u32)>) -> u32 {
+	hands_and_bids.sort_by_key(|a| a.0);
+
+	hands_and_bids
+		.iter()
+		.zip(1u32..)
+		.fold(Checked(0), |total, (&(_hand, bid), rank)| {
+			total + Checked(bid) * rank
+		})
+		.get()
+}
+
+pub struct Day07 {
+	hands_and_bids: Vec<(Hand, u32)>,
+}
+
+impl Solution for Day07 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut hands_and_bids = Vec::new();
+		for line in input.lines() {
+			let (hand, bid) = parse::split_once(input, line, " ")?;
+			hands_and_bids.push((Hand::parse(input, hand)?, parse::number(input, bid)?));
+		}
+		Ok(Day07 { hands_and_bids })
+	}
+
+	fn part1(&self) -> Answer {
+		total_winnings(self.hands_and_bids.clone()).into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let with_jokers = self
+			.hands_and_bids
+			.iter()
+			.map(|&(hand, bid)| (hand.with_jokers(), bid))
+			.collect();
+		Some(total_winnings(with_jokers).into())
+	}
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::{
+	cycle, math,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Eq, PartialEq, Hash)]
+struct Node {
+	name: [u8; 3],
+	left: [u8; 3],
+	right: [u8; 3],
+}
+
+impl Node {
+	pub fn parse(input: &str, line: &str) -> Result<Node, ParseError> {
+		let name = |cursor: &mut parse::Cursor| {
+			let text = cursor.take(3, "a three-letter node name")?;
+			<[u8; 3]>::try_from(text.as_bytes())
+				.map_err(|_| ParseError::at(input, text, "a three-letter node name"))
+		};
+		let mut cursor = parse::Cursor::new(input, line);
+		let node = name(&mut cursor)?;
+		cursor.expect(" = (")?;
+		let left = name(&mut cursor)?;
+		cursor.expect(", ")?;
+		let right = name(&mut cursor)?;
+		cursor.expect(")")?;
+		cursor.finish()?;
+		Ok(Node {
+			name: node,
+			left,
+			right,
+		})
+	}
+
+	pub fn follow<'a>(
+		&'a self,
+		instructions: &'a str,
+		start_index: usize,
+		world: &'a HashMap<[u8; 3], Node>,
+	) -> Follower<'a> {
+		Follower {
+			instructions,
+			world,
+			current_node: self,
+			current_index: start_index,
+		}
+	}
+}
+
+struct Follower<'a> {
+	instructions: &'a str,
+	current_node: &'a Node,
+	current_index: usize,
+	world: &'a HashMap<[u8; 3], Node>,
+}
+
+impl<'a> Iterator for Follower<'a> {
+	type Item = (usize, &'a Node);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let inst = self.instructions.as_bytes()[self.current_index];
+		self.current_index = (self.current_index + 1) % self.instructions.len();
+		match inst {
+			b'L' => self.current_node = self.world.get(&self.current_node.left).unwrap(),
+			b'R' => self.current_node = self.world.get(&self.current_node.right).unwrap(),
+			_ => {},
+		}
+		Some((self.current_index, self.current_node))
+	}
+}
+
+impl Debug for Node {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{name} = ({left}, {right})",
+			name = std::str::from_utf8(&self.name).unwrap(),
+			left = std::str::from_utf8(&self.left).unwrap(),
+			right = std::str::from_utf8(&self.right).unwrap(),
+		)
+	}
+}
+
+pub struct Day08 {
+	instructions: String,
+	nodes: HashMap<[u8; 3], Node>,
+}
+
+impl Solution for Day08 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut sections = parse::sections(input);
+
+		let mut nodes: HashMap<[u8; 3], Node> = HashMap::new();
+
+		let instructions = sections
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a line of instructions"))?;
+		parse::chars(input, instructions, "L or R", |c| {
+			"LR".contains(c).then_some(c)
+		})?;
+		let instructions = instructions.to_string();
+		let network = sections
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a network of nodes"))?;
+
+		for line in network.lines() {
+			let node = Node::parse(input, line)?;
+			nodes.insert(node.name, node);
+		}
+
+		Ok(Day08 {
+			instructions,
+			nodes,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let start = self.nodes.get(b"AAA").unwrap();
+		let mut steps: u32 = 0;
+
+		for (_, n) in start.follow(&self.instructions, 0, &self.nodes) {
+			steps += 1;
+			if n.name == *b"ZZZ" {
+				break;
+			}
+		}
+
+		steps.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let starts = self.nodes.values().filter(|n| n.name[2] == b'A');
+
+		// the walk from each start ends up going round a loop through one of the Z nodes, and
+		// the loops line up once every walk has been round its own a whole number of times
+		let loop_sizes = starts.map(|s| {
+			let period = cycle::brent((0, s), |&(i, n)| {
+				n.follow(&self.instructions, i, &self.nodes).next().unwrap()
+			});
+			period.length as u64
+		});
+
+		let steps = math::lcm_all(loop_sizes).expect("the loops take too long to line up");
+		Some(steps.into())
+	}
+}
@@ -0,0 +1,82 @@
+use crate::{
+	checked::Checked,
+	iter::SliceExt,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+/// Repeatedly takes differences of the readings until they're all zero, returning every
+/// track from the readings themselves down to the zeros.
+fn difference_tracks(readings: &[i64]) -> Vec<Vec<i64>> {
+	let mut difference_tracks: Vec<Vec<i64>> = vec![readings.to_vec()];
+
+	// calculate differences from input
+	while !difference_tracks.last().unwrap().iter().all(|&d| d == 0) {
+		let last: &[i64] = difference_tracks.last().unwrap();
+		let new_differences: Vec<i64> = SliceExt::array_windows::<2>(last)
+			.map(|&[a, b]| (Checked(b) - a).get())
+			.collect();
+		difference_tracks.push(new_differences);
+	}
+
+	difference_tracks
+}
+
+fn extrapolate_forward(readings: &[i64]) -> i64 {
+	let mut difference_tracks = difference_tracks(readings);
+
+	// propagate upward
+	difference_tracks.last_mut().unwrap().push(0);
+	for i in (1..difference_tracks.len()).rev() {
+		let upper = &difference_tracks[i - 1];
+		let lower = &difference_tracks[i];
+		let new_term = (Checked(*lower.last().unwrap()) + *upper.last().unwrap()).get();
+		difference_tracks[i - 1].push(new_term);
+	}
+	*difference_tracks[0].last().unwrap()
+}
+
+fn extrapolate_backward(readings: &[i64]) -> i64 {
+	let mut difference_tracks = difference_tracks(readings);
+
+	// propagate upward
+	difference_tracks.last_mut().unwrap().push(0);
+	for i in (1..difference_tracks.len()).rev() {
+		let upper = &difference_tracks[i - 1];
+		let lower = &difference_tracks[i];
+		let new_term = (Checked(upper[0]) - lower[0]).get();
+		difference_tracks[i - 1].insert(0, new_term);
+	}
+	difference_tracks[0][0]
+}
+
+pub struct Day09 {
+	histories: Vec<Vec<i64>>,
+}
+
+impl Solution for Day09 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day09 {
+			histories: input
+				.lines()
+				.map(|line| parse::numbers(input, line))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let sum = self
+			.histories
+			.iter()
+			.fold(Checked(0i64), |sum, h| sum + extrapolate_forward(h));
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let sum = self
+			.histories
+			.iter()
+			.fold(Checked(0i64), |sum, h| sum + extrapolate_backward(h));
+		Some(sum.into())
+	}
+}
@@ -0,0 +1,163 @@
+use crate::{
+	flood::{self, Connectivity},
+	geometry::{
+		Direction::{self, *},
+		Point,
+	},
+	grid::Grid,
+	parse::ParseError,
+	polygon::Polygon,
+	Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pipe {
+	Vert,
+	Horiz,
+	Ne,
+	Nw,
+	Sw,
+	Se,
+	Ground,
+	Start,
+}
+
+use Pipe::*;
+
+impl TryFrom<char> for Pipe {
+	type Error = char;
+
+	fn try_from(value: char) -> Result<Self, Self::Error> {
+		match value {
+			'|' => Ok(Vert),
+			'-' => Ok(Horiz),
+			'L' => Ok(Ne),
+			'J' => Ok(Nw),
+			'7' => Ok(Sw),
+			'F' => Ok(Se),
+			'.' => Ok(Ground),
+			'S' => Ok(Start),
+			c => Err(c),
+		}
+	}
+}
+
+impl Pipe {
+	/// The directions this pipe has openings in, as a mask of `Direction::bit`s.
+	fn openings(&self) -> u8 {
+		match *self {
+			Vert => Up.bit() | Down.bit(),
+			Horiz => Left.bit() | Right.bit(),
+			Ne => Up.bit() | Right.bit(),
+			Nw => Up.bit() | Left.bit(),
+			Sw => Down.bit() | Left.bit(),
+			Se => Down.bit() | Right.bit(),
+			Ground => 0,
+			Start => 0,
+		}
+	}
+
+	pub fn connects(&self, direction: Direction) -> bool {
+		self.openings() & direction.bit() != 0
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Sketch {
+	grid: Grid<Pipe>,
+	start: (usize, usize),
+}
+
+impl Sketch {
+	fn parse(input: &str) -> Result<Sketch, ParseError> {
+		let grid: Grid<Pipe> = Grid::parse(input, input.lines(), "a pipe")?;
+		let Some(start) = grid.position(|&p| p == Start) else {
+			return Err(ParseError::end(input, "a starting position S"));
+		};
+		Ok(Sketch { grid, start })
+	}
+
+	fn reachable<'a>(
+		grid: &Grid<Pipe>,
+		(x, y): (usize, usize),
+		buf: &'a mut [(usize, usize); 4],
+	) -> &'a [(usize, usize)] {
+		let mut count: usize = 0;
+		let current_pipe = grid[(x, y)];
+
+		for direction in Direction::ALL {
+			let Some(new_pos) = grid.step((x, y), direction.offset().into()) else {
+				continue;
+			};
+			if grid[new_pos] == Ground {
+				continue;
+			}
+			if current_pipe == Start {
+				if !grid[new_pos].connects(direction.reverse()) {
+					continue;
+				}
+			} else if !current_pipe.connects(direction) {
+				continue;
+			}
+			buf[count] = new_pos;
+			count += 1;
+		}
+		&buf[0..count]
+	}
+
+	/// How many steps along the loop it is from the start to the tile farthest from it.
+	fn max_distance(&self) -> usize {
+		let mut buf = [(0usize, 0usize); 4];
+		let pipe_loop = flood::fill(&self.grid, [self.start], Connectivity::Four, |from, to| {
+			Sketch::reachable(&self.grid, from, &mut buf).contains(&to)
+		});
+		pipe_loop.max_distance().unwrap()
+	}
+
+	/// The tiles of the loop through the start, in the order they're connected.
+	fn trace_loop(&self) -> Vec<(usize, usize)> {
+		let mut buf = [(0usize, 0usize); 4];
+		let mut tiles = vec![self.start];
+		let mut previous = self.start;
+		let mut current = Sketch::reachable(&self.grid, self.start, &mut buf)[0];
+
+		while current != self.start {
+			tiles.push(current);
+			let next = *Sketch::reachable(&self.grid, current, &mut buf)
+				.iter()
+				.find(|&&pos| pos != previous)
+				.expect("the loop has a dead end");
+			previous = current;
+			current = next;
+		}
+
+		tiles
+	}
+}
+
+pub struct Day10 {
+	sketch: Sketch,
+}
+
+impl Solution for Day10 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day10 {
+			sketch: Sketch::parse(input)?,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		self.sketch.max_distance().into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let tiles = self.sketch.trace_loop();
+		let pipe_loop = Polygon::from_vertices(
+			tiles
+				.into_iter()
+				.map(|(x, y)| Point::new(x as i64, y as i64)),
+		);
+
+		Some(pipe_loop.interior_points().into())
+	}
+}
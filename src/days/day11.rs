@@ -0,0 +1,78 @@
+use crate::{
+	bits::BitGrid,
+	checked::Checked,
+	grid::Grid,
+	params::{Param, Params},
+	parse::ParseError,
+	Answer, Solution,
+};
+
+/// The sum of the distances between every pair of galaxies, once each empty row and column has
+/// grown to `expansion` rows or columns.
+fn sum_distances(space: &BitGrid, expansion: i64) -> i64 {
+	// where each row and column ends up after the empty ones before it have grown
+	let expanded = |empty: Vec<bool>| -> Vec<i64> {
+		empty
+			.iter()
+			.scan(0i64, |next, &empty| {
+				let position = *next;
+				*next = (Checked(*next) + if empty { expansion } else { 1 }).get();
+				Some(position)
+			})
+			.collect()
+	};
+	let ys = expanded(space.rows().map(|r| r.is_empty()).collect());
+	let xs = expanded(space.columns().map(|c| c.is_empty()).collect());
+
+	let galaxies: Vec<(i64, i64)> = space.ones().map(|(x, y)| (xs[x], ys[y])).collect();
+
+	let mut sum_distances = Checked(0i64);
+
+	for (i, &(x1, y1)) in galaxies.iter().enumerate() {
+		for &(x2, y2) in &galaxies[i + 1..] {
+			// positions are never negative, so only the sums can overflow
+			let distance = Checked((x2 - x1).abs()) + (y2 - y1).abs();
+			sum_distances += distance;
+		}
+	}
+
+	sum_distances.get()
+}
+
+pub struct Day11 {
+	space: BitGrid,
+	params: Params,
+}
+
+impl Solution for Day11 {
+	const PARAMS: &'static [Param] = &[Param::new(
+		"expansion",
+		1_000_000,
+		"how many rows or columns each empty one grows to in part 2",
+	)
+	.range(1, i64::MAX)];
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let space = Grid::parse_with(input, input.lines(), ". or #", |c| match c {
+			'.' => Some(false),
+			'#' => Some(true),
+			_ => None,
+		})?;
+		Ok(Day11 {
+			space: BitGrid::from(&space),
+			params: Params::defaults(Self::PARAMS),
+		})
+	}
+
+	fn configure(&mut self, params: &Params) {
+		self.params = params.clone();
+	}
+
+	fn part1(&self) -> Answer {
+		sum_distances(&self.space, 2).into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		Some(sum_distances(&self.space, self.params.get("expansion")).into())
+	}
+}
@@ -0,0 +1,128 @@
+use crate::{
+	memo::Memo,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Condition {
+	Operational,
+	Damaged,
+	Unknown,
+}
+
+use Condition::*;
+
+/// Arrangements of the springs that are left, keyed by how many springs and how many damaged
+/// groups are left, since they're always tails of the row's.
+type Cache = Memo<(usize, usize), usize>;
+
+#[derive(Debug)]
+struct Row {
+	conditions: Vec<Condition>,
+	damaged_groups: Vec<usize>,
+}
+
+impl Row {
+	fn parse(input: &str, line: &str) -> Result<Row, ParseError> {
+		let (conditions_part, groups_part) = parse::split_once(input, line, " ")?;
+
+		let conditions = parse::chars(input, conditions_part, "#, . or ?", |c| match c {
+			'#' => Some(Damaged),
+			'.' => Some(Operational),
+			'?' => Some(Unknown),
+			_ => None,
+		})?;
+		let damaged_groups = parse::list(groups_part, ",", |n| parse::number(input, n))?;
+
+		Ok(Row {
+			conditions,
+			damaged_groups,
+		})
+	}
+}
+
+impl Row {
+	fn assign_operational(
+		cache: &mut Cache,
+		conditions: &[Condition],
+		damaged_groups: &[usize],
+	) -> usize {
+		Row::count_arrangements_internal(cache, &conditions[1..], damaged_groups)
+	}
+
+	/// The first spring is damaged, so the next damaged group has to start right here.
+	fn assign_damaged(
+		cache: &mut Cache,
+		conditions: &[Condition],
+		damaged_groups: &[usize],
+	) -> usize {
+		let (group, rest) = if let Some((&group, rest)) = damaged_groups.split_first() {
+			(group, rest)
+		} else {
+			return 0;
+		};
+
+		if conditions.len() < group || conditions[..group].contains(&Operational) {
+			0
+		} else if conditions.len() == group {
+			if rest.is_empty() {
+				1
+			} else {
+				0
+			}
+		} else if conditions[group] == Damaged {
+			// the group would be too long
+			0
+		} else {
+			// the spring after the group has to be operational
+			Row::count_arrangements_internal(cache, &conditions[(group + 1)..], rest)
+		}
+	}
+
+	fn count_arrangements_internal(
+		cache: &mut Cache,
+		conditions: &[Condition],
+		damaged_groups: &[usize],
+	) -> usize {
+		if conditions.is_empty() {
+			return if damaged_groups.is_empty() { 1 } else { 0 };
+		}
+		let key = (conditions.len(), damaged_groups.len());
+		cache.get_or_compute(key, |cache| match conditions[0] {
+			Operational => Row::assign_operational(cache, conditions, damaged_groups),
+			Damaged => Row::assign_damaged(cache, conditions, damaged_groups),
+			Unknown => {
+				Row::assign_operational(cache, conditions, damaged_groups)
+					+ Row::assign_damaged(cache, conditions, damaged_groups)
+			},
+		})
+	}
+
+	pub fn count_arrangements(&self) -> usize {
+		let mut cache = Cache::new();
+		Row::count_arrangements_internal(&mut cache, &self.conditions, &self.damaged_groups)
+	}
+}
+
+pub struct Day12 {
+	rows: Vec<Row>,
+}
+
+impl Solution for Day12 {
+	const HAS_PART2: bool = false;
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day12 {
+			rows: input
+				.lines()
+				.map(|l| Row::parse(input, l))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let sum: usize = self.rows.iter().map(|r| r.count_arrangements()).sum();
+		sum.into()
+	}
+}
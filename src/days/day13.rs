@@ -0,0 +1,69 @@
+use crate::{
+	bits::BitGrid,
+	grid::Grid,
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+/// The number of rows above a horizontal line of reflection where exactly `smudges` cells
+/// differ from their mirror image.
+fn reflection_row(grid: &BitGrid, smudges: usize) -> Option<usize> {
+	for row in 0..(grid.height() - 1) {
+		let top_rows = (0..=row).rev().map(|y| grid.row(y));
+		let bottom_rows = ((row + 1)..grid.height()).map(|y| grid.row(y));
+
+		if top_rows
+			.zip(bottom_rows)
+			.map(|(r1, r2)| r1.xor(r2).len())
+			.sum::<usize>()
+			== smudges
+		{
+			return Some(row + 1);
+		}
+	}
+	None
+}
+
+/// Finds the line of reflection where exactly `smudges` cells differ from their mirror image,
+/// and summarizes it as the puzzle asks (columns to the left, or 100 times rows above).
+fn find_reflection(grid: &BitGrid, smudges: usize) -> usize {
+	// a vertical line is a horizontal one once rows and columns are swapped
+	if let Some(columns) = reflection_row(&grid.transpose(), smudges) {
+		return columns;
+	}
+	if let Some(rows) = reflection_row(grid, smudges) {
+		return 100 * rows;
+	}
+	unreachable!();
+}
+
+pub struct Day13 {
+	patterns: Vec<BitGrid>,
+}
+
+impl Solution for Day13 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut patterns: Vec<BitGrid> = Vec::new();
+
+		for pattern in parse::sections(input) {
+			let grid = Grid::parse_with(input, pattern.lines(), ". or #", |c| match c {
+				'#' => Some(true),
+				'.' => Some(false),
+				_ => None,
+			})?;
+			patterns.push(BitGrid::from(&grid));
+		}
+
+		Ok(Day13 { patterns })
+	}
+
+	fn part1(&self) -> Answer {
+		let sum: usize = self.patterns.iter().map(|g| find_reflection(g, 0)).sum();
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let sum: usize = self.patterns.iter().map(|g| find_reflection(g, 1)).sum();
+		Some(sum.into())
+	}
+}
@@ -0,0 +1,116 @@
+use crate::{
+	cycle,
+	grid::Grid,
+	params::{Param, Params},
+	parse::ParseError,
+	Answer, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum GridPos {
+	Empty,
+	Rounded,
+	Cube,
+}
+
+use GridPos::*;
+
+impl From<GridPos> for char {
+	fn from(value: GridPos) -> Self {
+		match value {
+			Empty => '.',
+			Rounded => 'O',
+			Cube => '#',
+		}
+	}
+}
+
+impl TryFrom<char> for GridPos {
+	type Error = char;
+	fn try_from(value: char) -> Result<Self, Self::Error> {
+		match value {
+			'.' => Ok(Empty),
+			'O' => Ok(Rounded),
+			'#' => Ok(Cube),
+			c => Err(c),
+		}
+	}
+}
+
+fn roll_north(grid: &mut Grid<GridPos>) {
+	let mut rolled = true;
+	while rolled {
+		rolled = false;
+		for y in (1..grid.height()).rev() {
+			for x in 0..grid.width() {
+				if grid[(x, y)] != Rounded {
+					continue;
+				}
+				if grid[(x, y - 1)] == Empty {
+					grid[(x, y - 1)] = Rounded;
+					grid[(x, y)] = Empty;
+					rolled = true;
+				}
+			}
+		}
+	}
+}
+
+/// Rolls the rocks north, west, south and east. Turning the platform clockwise after each roll
+/// brings the next direction to the top, and four turns leave it the way it started.
+fn spin_cycle(grid: &mut Grid<GridPos>) {
+	for _ in 0..4 {
+		roll_north(grid);
+		*grid = grid.rotate_cw();
+	}
+}
+
+fn count_load(grid: &Grid<GridPos>) -> usize {
+	let mut load: usize = 0;
+	for (y, row) in grid.rows().enumerate() {
+		for rock in row.iter() {
+			if *rock == Rounded {
+				load += grid.height() - y;
+			}
+		}
+	}
+	load
+}
+
+pub struct Day14 {
+	grid: Grid<GridPos>,
+	params: Params,
+}
+
+impl Solution for Day14 {
+	const PARAMS: &'static [Param] =
+		&[Param::new("cycles", 1_000_000_000, "spin cycles to run in part 2").range(0, i64::MAX)];
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let grid = Grid::parse(input, input.lines(), "., O or #")?;
+		Ok(Day14 {
+			grid,
+			params: Params::defaults(Self::PARAMS),
+		})
+	}
+
+	fn configure(&mut self, params: &Params) {
+		self.params = params.clone();
+	}
+
+	fn part1(&self) -> Answer {
+		let mut grid = self.grid.clone();
+		roll_north(&mut grid);
+		count_load(&grid).into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let cycle = cycle::find(self.grid.clone(), |grid| {
+			let mut grid = grid.clone();
+			spin_cycle(&mut grid);
+			grid
+		});
+
+		Some(count_load(cycle.state_after(self.params.get("cycles"))).into())
+	}
+}
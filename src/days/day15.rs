@@ -0,0 +1,93 @@
+use crate::{
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Lens {
+	label: String,
+	focal_length: u8,
+}
+
+fn hash(s: &str) -> usize {
+	let mut hash: usize = 0;
+	for b in s.bytes() {
+		hash += b as usize;
+		hash *= 17;
+		hash %= 256;
+	}
+	hash
+}
+
+pub struct Day15 {
+	sequence: String,
+}
+
+impl Solution for Day15 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		for line in input.lines() {
+			for step in line.split(',') {
+				let Some(op) = step.find(['-', '=']) else {
+					return Err(ParseError::at(input, step, "a step with - or ="));
+				};
+				let (label, rest) = (&step[..op], &step[op + 1..]);
+				if label.is_empty() || !label.chars().all(|c| c.is_ascii_lowercase()) {
+					return Err(ParseError::at(input, label, "a label"));
+				}
+				if step[op..].starts_with('=') {
+					match parse::number::<u8>(input, rest)? {
+						1..=9 => {},
+						_ => return Err(ParseError::at(input, rest, "a focal length from 1 to 9")),
+					}
+				} else if !rest.is_empty() {
+					return Err(ParseError::at(input, rest, "nothing after -"));
+				}
+			}
+		}
+
+		Ok(Day15 {
+			sequence: input.chars().filter(|&c| c != '\n').collect(),
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let sum: usize = self.sequence.split(',').map(hash).sum();
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let mut boxes: [Vec<Lens>; 256] = std::array::from_fn(|_| Vec::new());
+
+		for step in self.sequence.split(',').map(|s| s.trim_end()) {
+			let label = step.split(&['-', '=']).nth(0).unwrap();
+			let hash = hash(label);
+
+			if step.ends_with('-') {
+				// remove given lens
+				if let Some(index) = boxes[hash].iter().position(|l| l.label == label) {
+					boxes[hash].remove(index);
+				}
+			} else {
+				let focal_length = *step.as_bytes().last().unwrap() - b'0';
+				if let Some(lens) = boxes[hash].iter_mut().find(|l| l.label == label) {
+					lens.focal_length = focal_length;
+				} else {
+					boxes[hash].push(Lens {
+						label: label.to_string(),
+						focal_length,
+					});
+				}
+			}
+		}
+
+		let mut sum: usize = 0;
+		for (i, contents) in boxes.iter().enumerate() {
+			for (j, lens) in contents.iter().enumerate() {
+				let focusing_power = (i + 1) * (j + 1) * lens.focal_length as usize;
+				sum += focusing_power;
+			}
+		}
+
+		Some(sum.into())
+	}
+}
@@ -0,0 +1,175 @@
+use std::ops::Range;
+
+use crate::{
+	geometry::Direction::{self, *},
+	grid::Grid,
+	parse::ParseError,
+	Answer, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+	Empty,
+	BackMirror,
+	FwdMirror,
+	HSplit,
+	VSplit,
+}
+
+use Tile::*;
+
+impl TryFrom<char> for Tile {
+	type Error = char;
+	fn try_from(value: char) -> Result<Self, Self::Error> {
+		match value {
+			'.' => Ok(Empty),
+			'\\' => Ok(BackMirror),
+			'/' => Ok(FwdMirror),
+			'-' => Ok(HSplit),
+			'|' => Ok(VSplit),
+			c => Err(c),
+		}
+	}
+}
+
+impl From<Tile> for char {
+	fn from(value: Tile) -> Self {
+		match value {
+			Empty => '.',
+			BackMirror => '\\',
+			FwdMirror => '/',
+			HSplit => '-',
+			VSplit => '|',
+		}
+	}
+}
+
+fn send_beam(
+	machine: &Grid<Tile>,
+	mut x: usize,
+	mut y: usize,
+	mut dir: Direction,
+	energized: &mut Grid<u8>,
+) {
+	loop {
+		if energized[(x, y)] & dir.bit() != 0 {
+			// we have been at this tile in this direction before
+			return;
+		}
+
+		energized[(x, y)] |= dir.bit();
+
+		match machine[(x, y)] {
+			BackMirror => {
+				dir = match dir {
+					Left => Up,
+					Right => Down,
+					Up => Left,
+					Down => Right,
+				}
+			},
+			FwdMirror => {
+				dir = match dir {
+					Left => Down,
+					Right => Up,
+					Up => Right,
+					Down => Left,
+				};
+			},
+			HSplit => {
+				if dir == Up || dir == Down {
+					// send both new beams, then return
+					send_beam(machine, x, y, Left, energized);
+					send_beam(machine, x, y, Right, energized);
+					return;
+				}
+			},
+			VSplit => {
+				if dir == Left || dir == Right {
+					send_beam(machine, x, y, Up, energized);
+					send_beam(machine, x, y, Down, energized);
+					return;
+				}
+			},
+			Empty => {},
+		}
+
+		if let Some((new_x, new_y)) = machine.step((x, y), dir.offset().into()) {
+			x = new_x;
+			y = new_y;
+		} else {
+			// beam left grid
+			return;
+		}
+	}
+}
+
+fn count_energized(energized: &Grid<u8>) -> usize {
+	energized.iter().filter(|&(_, &d)| d != 0).count()
+}
+
+fn maximize_energy(
+	machine: &Grid<Tile>,
+	x_range: Range<usize>,
+	y_range: Range<usize>,
+	dir: Direction,
+	energized: &mut Grid<u8>,
+) -> usize {
+	let mut max_energized: usize = 0;
+
+	for x in x_range {
+		for y in y_range.clone() {
+			energized.fill(0);
+			send_beam(machine, x, y, dir, energized);
+			max_energized = max_energized.max(count_energized(energized));
+		}
+	}
+
+	max_energized
+}
+
+pub struct Day16 {
+	machine: Grid<Tile>,
+}
+
+impl Solution for Day16 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let machine = Grid::parse(input, input.lines(), "., /, \\, | or -")?;
+		Ok(Day16 { machine })
+	}
+
+	fn part1(&self) -> Answer {
+		let machine = &self.machine;
+		let mut energized = Grid::new(machine.width(), machine.height(), 0u8);
+		send_beam(machine, 0, 0, Right, &mut energized);
+		count_energized(&energized).into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let machine = &self.machine;
+		let mut energized = Grid::new(machine.width(), machine.height(), 0u8);
+		let max_energized: usize = *[
+			maximize_energy(machine, 0..machine.width(), 0..1, Down, &mut energized),
+			maximize_energy(
+				machine,
+				0..machine.width(),
+				(machine.height() - 1)..machine.height(),
+				Up,
+				&mut energized,
+			),
+			maximize_energy(machine, 0..1, 0..machine.height(), Right, &mut energized),
+			maximize_energy(
+				machine,
+				(machine.width() - 1)..machine.width(),
+				0..machine.height(),
+				Left,
+				&mut energized,
+			),
+		]
+		.iter()
+		.max()
+		.unwrap();
+
+		Some(max_energized.into())
+	}
+}
@@ -0,0 +1,185 @@
+use crate::{
+	geometry::{Direction, Point},
+	grid::Grid,
+	params::{Param, Params},
+	parse::ParseError,
+	pathfinding, Answer, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Crucible {
+	position: Point<usize>,
+	direction: Direction,
+	time_straight: u8,
+}
+
+impl Crucible {
+	/// A crucible can only turn once it has moved `min_straight` blocks in a straight line, and
+	/// has to turn after `max_straight` blocks.
+	fn successors(
+		&self,
+		heat_loss: &Grid<u8>,
+		(min_straight, max_straight): (u8, u8),
+	) -> Vec<(Crucible, u32)> {
+		let mut successors = Vec::with_capacity(3);
+		let mut go = |direction: Direction, time_straight: u8| {
+			if let Some(position) =
+				self.position
+					.step(direction, heat_loss.width(), heat_loss.height())
+			{
+				let next = Crucible {
+					position,
+					direction,
+					time_straight,
+				};
+				successors.push((next, heat_loss[position] as u32));
+			}
+		};
+
+		if self.time_straight < max_straight {
+			go(self.direction, self.time_straight + 1);
+		}
+
+		if self.time_straight >= min_straight {
+			for new_dir in self.direction.turns() {
+				go(new_dir, 1);
+			}
+		}
+
+		successors
+	}
+}
+
+/// The crucibles that set off from the top left corner, either right or down.
+fn starts() -> [Crucible; 2] {
+	[Direction::Right, Direction::Down].map(|direction| Crucible {
+		position: Point::new(0, 0),
+		direction,
+		time_straight: 0,
+	})
+}
+
+/// Whether `crucible` can stop at the factory in the bottom right corner. It can't stop until
+/// it is able to turn, unless it never had to move at all.
+fn is_goal(heat_loss: &Grid<u8>, crucible: &Crucible, min_straight: u8) -> bool {
+	let goal = Point::new(heat_loss.width() - 1, heat_loss.height() - 1);
+	crucible.position == goal
+		&& (crucible.time_straight >= min_straight || goal == Point::new(0, 0))
+}
+
+/// Whether a crucible with these limits can get to the factory at all, whatever it costs.
+fn reaches_factory(heat_loss: &Grid<u8>, straight_limits: (u8, u8)) -> bool {
+	pathfinding::bfs(
+		starts(),
+		|c| {
+			c.successors(heat_loss, straight_limits)
+				.into_iter()
+				.map(|(next, _)| next)
+		},
+		|c| is_goal(heat_loss, c, straight_limits.0),
+	)
+	.is_some()
+}
+
+/// The least heat lost on the way to the factory, which `reaches_factory` has to have checked
+/// can be reached.
+fn minimize_heat_loss(heat_loss: &Grid<u8>, straight_limits: (u8, u8)) -> u32 {
+	let goal = Point::new(heat_loss.width() - 1, heat_loss.height() - 1);
+
+	let path = pathfinding::astar(
+		starts(),
+		|c| c.successors(heat_loss, straight_limits),
+		// manhattan distance, as every block loses at least 1 heat
+		|c| c.position.manhattan(goal) as u32,
+		|c| is_goal(heat_loss, c, straight_limits.0),
+	);
+
+	path.expect("no path to the factory").cost
+}
+
+pub struct Day17 {
+	heat_loss: Grid<u8>,
+	params: Params,
+}
+
+impl Solution for Day17 {
+	const PARAMS: &'static [Param] = &[
+		Param::new(
+			"max_straight",
+			3,
+			"most blocks a crucible can go straight in part 1",
+		)
+		.range(1, u8::MAX as i64),
+		Param::new(
+			"ultra_min_straight",
+			4,
+			"fewest blocks an ultra crucible goes straight in part 2",
+		)
+		.range(1, u8::MAX as i64),
+		Param::new(
+			"ultra_max_straight",
+			10,
+			"most blocks an ultra crucible can go straight in part 2",
+		)
+		.range(1, u8::MAX as i64),
+	];
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let heat_loss = Grid::parse_with(input, input.lines(), "a digit", |c| {
+			c.to_digit(10).map(|d| d as u8)
+		})?;
+		Ok(Day17 {
+			heat_loss,
+			params: Params::defaults(Self::PARAMS),
+		})
+	}
+
+	fn check_params(params: &Params) -> Result<(), String> {
+		let min: u8 = params.get("ultra_min_straight");
+		let max: u8 = params.get("ultra_max_straight");
+		if min > max {
+			return Err(format!(
+				"ultra_min_straight ({min}) can't be more than ultra_max_straight ({max})"
+			));
+		}
+		Ok(())
+	}
+
+	fn configure(&mut self, params: &Params) {
+		self.params = params.clone();
+	}
+
+	fn check_input(&self, input: &str) -> Result<(), ParseError> {
+		let limits = [
+			(1, self.params.get("max_straight")),
+			(
+				self.params.get("ultra_min_straight"),
+				self.params.get("ultra_max_straight"),
+			),
+		];
+		for (min, max) in limits {
+			if !reaches_factory(&self.heat_loss, (min, max)) {
+				// point at the factory, the last block of the grid
+				let last_row = input.lines().last().unwrap_or(input);
+				return Err(ParseError::at(
+					input,
+					&last_row[last_row.len() - 1..],
+					format!("a factory a crucible can reach going {min} to {max} blocks straight"),
+				));
+			}
+		}
+		Ok(())
+	}
+
+	fn part1(&self) -> Answer {
+		minimize_heat_loss(&self.heat_loss, (1, self.params.get("max_straight"))).into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let limits = (
+			self.params.get("ultra_min_straight"),
+			self.params.get("ultra_max_straight"),
+		);
+		Some(minimize_heat_loss(&self.heat_loss, limits).into())
+	}
+}
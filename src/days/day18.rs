@@ -0,0 +1,147 @@
+use std::fmt::Debug;
+
+use crate::{
+	flood::{self, Connectivity},
+	geometry::{Direction, Point},
+	grid::Grid,
+	iter::IterExt,
+	parse::{self, ParseError},
+	polygon::Polygon,
+	Answer, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DigState {
+	Ground,
+	Dug,
+}
+
+use DigState::*;
+
+struct Digger {
+	position: Point<usize>,
+	dug: Grid<DigState>,
+}
+
+impl Digger {
+	/// A digger on ground big enough for the whole plan, with a ring of ground left around the
+	/// trench so that it can be filled from a corner.
+	pub fn new(plan: &[PlanLine]) -> Digger {
+		let mut position = Point::new(0i64, 0i64);
+		let (mut min, mut max) = (position, position);
+		for line in plan {
+			position = position + line.direction.offset() * line.distance as i64;
+			min = Point::new(min.x.min(position.x), min.y.min(position.y));
+			max = Point::new(max.x.max(position.x), max.y.max(position.y));
+		}
+
+		let size = max - min + Point::new(3, 3);
+		let start = Point::new(1, 1) - min;
+		let position = Point::new(start.x as usize, start.y as usize);
+		let mut dug = Grid::new(size.x as usize, size.y as usize, Ground);
+		dug[position] = Dug;
+		Digger { position, dug }
+	}
+
+	fn dig(&mut self, direction: Direction) {
+		self.position = self
+			.position
+			.step(direction, self.dug.width(), self.dug.height())
+			.expect("the ground was measured to fit the plan");
+		self.dug[self.position] = Dug;
+	}
+
+	/// count the tiles in or enclosed by the trench
+	fn count_dug(&self) -> usize {
+		// everything the ground around the edge connects to is outside
+		let outside = flood::fill(&self.dug, [(0, 0)], Connectivity::Four, |_, to| {
+			self.dug[to] == Ground
+		});
+		self.dug.width() * self.dug.height() - outside.size
+	}
+}
+
+impl Debug for Digger {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let mut dug = self.dug.map(|&dug| match dug {
+			Ground => '.',
+			Dug => '#',
+		});
+		dug[self.position] = 'x';
+		write!(f, "{}", dug.render(|&c| c).trim_end())
+	}
+}
+
+struct PlanLine {
+	direction: Direction,
+	distance: usize,
+	/// what part 2 reads from the color: a direction and distance in hex
+	hex_direction: Direction,
+	hex_distance: i64,
+}
+
+impl PlanLine {
+	fn parse(input: &str, line: &str) -> Result<PlanLine, ParseError> {
+		let (direction, rest) = parse::split_once(input, line, " ")?;
+		let (distance, color) = parse::split_once(input, rest, " ")?;
+
+		let letter = direction.chars().collect_array();
+		let Some(direction) = letter.and_then(|[letter]| Direction::from_letter(letter)) else {
+			return Err(ParseError::at(input, direction, "R, L, U or D"));
+		};
+		let distance = parse::number(input, distance)?;
+
+		let color = parse::strip_prefix(input, color, "(#")?;
+		let hex = color.strip_suffix(')').filter(|hex| hex.len() == 6);
+		let Some(hex) = hex.filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit())) else {
+			return Err(ParseError::at(input, color, "six hex digits and \")\""));
+		};
+		let hex_direction = Direction::from_digit(hex.as_bytes()[5] as char)
+			.ok_or_else(|| ParseError::at(input, &hex[5..], "a direction from 0 to 3"))?;
+		let hex_distance = i64::from_str_radix(&hex[..5], 16).unwrap();
+
+		Ok(PlanLine {
+			direction,
+			distance,
+			hex_direction,
+			hex_distance,
+		})
+	}
+}
+
+pub struct Day18 {
+	plan: Vec<PlanLine>,
+}
+
+impl Solution for Day18 {
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		Ok(Day18 {
+			plan: input
+				.lines()
+				.map(|l| PlanLine::parse(input, l))
+				.collect::<Result<_, _>>()?,
+		})
+	}
+
+	fn part1(&self) -> Answer {
+		let mut d = Digger::new(&self.plan);
+
+		for line in &self.plan {
+			for _ in 0..line.distance {
+				d.dig(line.direction);
+			}
+		}
+
+		d.count_dug().into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let trench = Polygon::from_moves(
+			Point::new(0, 0),
+			self.plan
+				.iter()
+				.map(|line| (line.hex_direction, line.hex_distance)),
+		);
+		Some(trench.tiles_covered().into())
+	}
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use crate::{
+	checked::Checked,
+	interval::{Interval, IntervalBox},
+	params::{Param, Params},
+	parse::{self, ParseError},
+	Answer, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Property {
+	X,
+	M,
+	A,
+	S,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Comparison {
+	Greater,
+	Less,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Destination {
+	Accept,
+	Reject,
+	Workflow(String),
+}
+
+impl Destination {
+	fn count_accepted(
+		&self,
+		workflows: &HashMap<String, Workflow>,
+		ranges: Ranges,
+	) -> Result<u64, &'static str> {
+		match self {
+			Destination::Accept => u64::try_from(ranges.volume()).map_err(|_| "negative volume"),
+			Destination::Reject => Ok(0),
+			Destination::Workflow(name) => workflows
+				.get(name)
+				.ok_or("workflow not found")?
+				.count_accepted(workflows, ranges),
+		}
+	}
+}
+
+impl Destination {
+	fn parse(name: &str) -> Destination {
+		match name {
+			"A" => Destination::Accept,
+			"R" => Destination::Reject,
+			workflow_name => Destination::Workflow(workflow_name.to_string()),
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Rule {
+	Compare {
+		property: Property,
+		comparison: Comparison,
+		value: i64,
+		destination: Destination,
+	},
+	Unconditional(Destination),
+}
+
+impl Rule {
+	fn count_accepted(
+		&self,
+		workflows: &HashMap<String, Workflow>,
+		ranges: Ranges,
+		next: &[Rule],
+	) -> Result<u64, &'static str> {
+		match self {
+			Rule::Unconditional(dest) => dest.count_accepted(workflows, ranges),
+			Rule::Compare {
+				property,
+				comparison,
+				value,
+				destination,
+			} => {
+				let axis = *property as usize;
+				let (if_true, if_false) = if *comparison == Comparison::Greater {
+					// suppose condition is x > 500, and X range is currently 0..=1000
+					// for "if true", we pass 501..=1000 into the target workflow
+					// for "if false", we follow the rest of this workflow's rules with 0..=500
+					let (below, above) = ranges.split_at(axis, (Checked(*value) + 1).get());
+					(above, below)
+				} else {
+					ranges.split_at(axis, *value)
+				};
+				let accepted = Checked(destination.count_accepted(workflows, if_true)?)
+					+ next[0].count_accepted(workflows, if_false, &next[1..])?;
+				Ok(accepted.get())
+			},
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Part {
+	x: i64,
+	m: i64,
+	a: i64,
+	s: i64,
+}
+
+impl Part {
+	fn get(&self, property: Property) -> i64 {
+		match property {
+			Property::X => self.x,
+			Property::M => self.m,
+			Property::A => self.a,
+			Property::S => self.s,
+		}
+	}
+}
+
+impl Part {
+	fn parse(input: &str, line: &str) -> Result<Part, ParseError> {
+		let ratings = parse::strip_prefix(input, line, "{")?;
+		let ratings = ratings
+			.strip_suffix('}')
+			.ok_or_else(|| ParseError::at(input, &ratings[ratings.len()..], "\"}\""))?;
+
+		let fields = parse::key_values(input, ratings, ",", "=")?;
+		let mut values = [0; 4];
+		for (i, name) in ["x", "m", "a", "s"].into_iter().enumerate() {
+			let Some(&(key, value)) = fields.get(i) else {
+				let end = &ratings[ratings.len()..];
+				return Err(ParseError::at(input, end, format!("\",{name}=\"")));
+			};
+			if key != name {
+				return Err(ParseError::at(input, key, format!("{name:?}")));
+			}
+			values[i] = rating(input, value)?;
+		}
+		if let Some(&(extra, _)) = fields.get(4) {
+			return Err(ParseError::at(input, extra, "only x, m, a and s"));
+		}
+
+		let [x, m, a, s] = values;
+		Ok(Part { x, m, a, s })
+	}
+}
+
+/// Parses a rating, which can't be negative and has to fit in the `i64`s intervals are made
+/// of.
+fn rating(input: &str, text: &str) -> Result<i64, ParseError> {
+	let rating: u64 = parse::number(input, text)?;
+	i64::try_from(rating).map_err(|_| ParseError::at(input, text, "a rating that fits in i64"))
+}
+
+/// The ratings a part could have along each property, in the order of `Property`.
+type Ranges = IntervalBox<4>;
+
+#[derive(Debug)]
+struct Workflow {
+	name: String,
+	rules: Vec<Rule>,
+}
+
+impl Workflow {
+	/// Parses a line like `px{a<2006:qkq,m>2090:A,rfg}`. The names of the workflows it sends parts
+	/// to are added to `references`, so they can be checked once every workflow has been read.
+	fn parse<'a>(
+		input: &str,
+		line: &'a str,
+		references: &mut Vec<&'a str>,
+	) -> Result<Workflow, ParseError> {
+		let (name, rest) = parse::split_once(input, line, "{")?;
+		let rules_string = rest
+			.strip_suffix('}')
+			.ok_or_else(|| ParseError::at(input, &rest[rest.len()..], "\"}\""))?;
+		let mut rules: Vec<Rule> = Vec::new();
+
+		for rule_desc in rules_string.split(',') {
+			let Some((condition, destination)) = rule_desc.split_once(':') else {
+				// unconditional
+				references.push(rule_desc);
+				rules.push(Rule::Unconditional(Destination::parse(rule_desc)));
+				continue;
+			};
+			references.push(destination);
+
+			let property = match condition.get(0..1) {
+				Some("x") => Property::X,
+				Some("m") => Property::M,
+				Some("a") => Property::A,
+				Some("s") => Property::S,
+				_ => return Err(ParseError::at(input, condition, "x, m, a or s")),
+			};
+			let comparison = match condition.get(1..2) {
+				Some(">") => Comparison::Greater,
+				Some("<") => Comparison::Less,
+				_ => return Err(ParseError::at(input, &condition[1..], "> or <")),
+			};
+			rules.push(Rule::Compare {
+				property,
+				comparison,
+				value: rating(input, &condition[2..])?,
+				destination: Destination::parse(destination),
+			});
+		}
+
+		match rules.last() {
+			Some(Rule::Unconditional(_)) => Ok(Workflow {
+				name: name.to_string(),
+				rules,
+			}),
+			_ => Err(ParseError::at(
+				input,
+				rules_string,
+				"rules ending in one without a condition",
+			)),
+		}
+	}
+}
+
+impl Workflow {
+	fn accept(
+		&self,
+		other_workflows: &HashMap<String, Workflow>,
+		part: &Part,
+	) -> Result<bool, &'static str> {
+		for r in self.rules.iter() {
+			match r {
+				Rule::Unconditional(d) => match d {
+					Destination::Accept => return Ok(true),
+					Destination::Reject => return Ok(false),
+					Destination::Workflow(name) => {
+						return other_workflows
+							.get(name)
+							.ok_or("destination workflow not found")?
+							.accept(other_workflows, part)
+					},
+				},
+				Rule::Compare {
+					property,
+					comparison,
+					value,
+					destination,
+				} => {
+					let part_value = part.get(*property);
+					let passes = match comparison {
+						Comparison::Greater => part_value > *value,
+						Comparison::Less => part_value < *value,
+					};
+
+					if passes {
+						match destination {
+							Destination::Accept => return Ok(true),
+							Destination::Reject => return Ok(false),
+							Destination::Workflow(name) => {
+								return other_workflows
+									.get(name)
+									.ok_or("destination workflow not found")?
+									.accept(other_workflows, part)
+							},
+						}
+					}
+				},
+			}
+		}
+		Err("reached end without termination")
+	}
+
+	fn count_accepted(
+		&self,
+		workflows: &HashMap<String, Workflow>,
+		ranges: Ranges,
+	) -> Result<u64, &'static str> {
+		self.rules[0].count_accepted(workflows, ranges, &self.rules[1..])
+	}
+}
+
+pub struct Day19 {
+	workflows: HashMap<String, Workflow>,
+	parts: Vec<Part>,
+	params: Params,
+}
+
+impl Solution for Day19 {
+	const PARAMS: &'static [Param] = &[
+		// the number of combinations of four ratings has to fit in an i64
+		Param::new("min_rating", 1, "lowest rating a part can have in part 2").range(1, 50_000),
+		Param::new(
+			"max_rating",
+			4000,
+			"highest rating a part can have in part 2",
+		)
+		.range(1, 50_000),
+	];
+
+	fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut sections = parse::sections(input);
+		let workflows_section = sections
+			.next()
+			.ok_or_else(|| ParseError::end(input, "a list of workflows"))?;
+
+		let mut workflows: HashMap<String, Workflow> = HashMap::new();
+		let mut references: Vec<&str> = Vec::new();
+
+		for line in workflows_section.lines() {
+			let workflow = Workflow::parse(input, line, &mut references)?;
+			workflows.insert(workflow.name.clone(), workflow);
+		}
+
+		if !workflows.contains_key("in") {
+			return Err(ParseError::end(input, "a workflow named \"in\""));
+		}
+		for name in references {
+			if !matches!(name, "A" | "R") && !workflows.contains_key(name) {
+				return Err(ParseError::at(input, name, "A, R or a workflow's name"));
+			}
+		}
+
+		let parts = sections
+			.next()
+			.unwrap_or_default()
+			.lines()
+			.map(|line| Part::parse(input, line))
+			.collect::<Result<_, _>>()?;
+
+		Ok(Day19 {
+			workflows,
+			parts,
+			params: Params::defaults(Self::PARAMS),
+		})
+	}
+
+	fn check_params(params: &Params) -> Result<(), String> {
+		let min: i64 = params.get("min_rating");
+		let max: i64 = params.get("max_rating");
+		if min > max {
+			return Err(format!(
+				"min_rating ({min}) can't be more than max_rating ({max})"
+			));
+		}
+		Ok(())
+	}
+
+	fn configure(&mut self, params: &Params) {
+		self.params = params.clone();
+	}
+
+	fn part1(&self) -> Answer {
+		let mut sum = Checked(0i64);
+		let start_workflow = self.workflows.get("in").unwrap();
+
+		for p in &self.parts {
+			if start_workflow.accept(&self.workflows, p) == Ok(true) {
+				sum += Checked(p.x) + p.m + p.a + p.s;
+			}
+		}
+
+		sum.into()
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		let ratings =
+			Interval::inclusive(self.params.get("min_rating"), self.params.get("max_rating"));
+		let start_workflow = self.workflows.get("in").unwrap();
+		let num_accepted = start_workflow
+			.count_accepted(&self.workflows, Ranges::new([ratings; 4]))
+			.unwrap();
+		Some(num_accepted.into())
+	}
+}
@@ -0,0 +1,268 @@
+//! Talking to the Advent of Code site. Puzzle pages and inputs are cached on disk, so each file
+//! is downloaded at most once; sending answers is in `submit`.
+
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+use crate::{
+	answers::Expected,
+	puzzle::{self, Example},
+	Answer, Part,
+};
+
+pub const YEAR: u16 = 2023;
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/190n/aoc2023";
+
+/// Where the session token is read from when `AOC_SESSION` isn't set.
+pub const SESSION_PATH: &str = ".aoc/session";
+
+/// Where downloaded puzzle pages are kept. They aren't checked in, like real inputs.
+pub const PAGES_DIR: &str = ".aoc/pages";
+
+pub struct Client {
+	base_url: String,
+	session: String,
+	agent: ureq::Agent,
+}
+
+impl Client {
+	pub fn new(base_url: &str, session: &str) -> Client {
+		Client {
+			base_url: base_url.trim_end_matches('/').to_string(),
+			session: session.trim().to_string(),
+			agent: ureq::AgentBuilder::new()
+				.user_agent(USER_AGENT)
+				.timeout(Duration::from_secs(30))
+				.build(),
+		}
+	}
+
+	/// A client for the real site, with the session token from `AOC_SESSION` or `.aoc/session`.
+	pub fn from_env() -> Result<Client, String> {
+		let session = match env::var("AOC_SESSION") {
+			Ok(session) => session,
+			Err(_) => fs::read_to_string(SESSION_PATH).map_err(|_| {
+				format!("no session token: set AOC_SESSION or save it in {SESSION_PATH}")
+			})?,
+		};
+		Ok(Client::new(BASE_URL, &session))
+	}
+
+	fn url(&self, day: u8, path: &str) -> String {
+		format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+	}
+
+	fn get(&self, url: &str) -> Result<String, String> {
+		let request = self
+			.agent
+			.get(url)
+			.set("Cookie", &format!("session={}", self.session));
+		read_response(url, request.call())
+	}
+
+	fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, String> {
+		let request = self
+			.agent
+			.post(url)
+			.set("Cookie", &format!("session={}", self.session));
+		read_response(url, request.send_form(form))
+	}
+
+	pub fn puzzle_page(&self, day: u8) -> Result<String, String> {
+		self.get(&self.url(day, ""))
+	}
+
+	pub fn input(&self, day: u8) -> Result<String, String> {
+		self.get(&self.url(day, "/input"))
+	}
+
+	/// Sends an answer and returns the page the site responds with.
+	pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<String, String> {
+		let level = part.to_string();
+		self.post(
+			&self.url(day, "/answer"),
+			&[("level", &level), ("answer", answer)],
+		)
+	}
+}
+
+fn read_response(
+	url: &str,
+	response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, String> {
+	let response = response.map_err(|e| match e {
+		ureq::Error::Status(code, _) => format!("{url} returned HTTP {code}"),
+		e => format!("couldn't fetch {url}: {e}"),
+	})?;
+	response
+		.into_string()
+		.map_err(|e| format!("couldn't read {url}: {e}"))
+}
+
+/// Where everything for one day is saved. `inputs` is laid out the way `aoc run` expects.
+pub struct Paths {
+	pub page: PathBuf,
+	pub input: PathBuf,
+	/// where examples go, as `exampleN.txt`, `exampleN-2.txt` and so on
+	pub examples: PathBuf,
+}
+
+impl Paths {
+	pub fn new(root: &Path, day: u8) -> Paths {
+		Paths {
+			page: root.join(PAGES_DIR).join(format!("day{day}.html")),
+			input: root.join("inputs").join(format!("input{day}.txt")),
+			examples: root.join("inputs"),
+		}
+	}
+}
+
+/// What `fetch_day` did with each file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+	Saved,
+	Cached,
+}
+
+/// An example block from the puzzle page and the file it's kept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedExample {
+	/// file name in `inputs/`, without the `.txt`
+	pub name: String,
+	pub outcome: Outcome,
+	pub example: Example,
+}
+
+impl SavedExample {
+	/// The example's guessed answer, in the form the answers registry uses.
+	pub fn proposal(&self, day: u8) -> Option<Expected> {
+		let answer = self.example.answer.as_deref()?;
+		Some(Expected {
+			input: self.name.clone(),
+			day,
+			part: self.example.part,
+			answer: match answer.parse() {
+				Ok(n) => Answer::Number(n),
+				Err(_) => Answer::Text(answer.to_string()),
+			},
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+	pub page: Outcome,
+	pub input: Outcome,
+	pub examples: Vec<SavedExample>,
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir).map_err(|e| format!("couldn't create {}: {e}", dir.display()))?;
+	}
+	fs::write(path, contents).map_err(|e| format!("couldn't write {}: {e}", path.display()))
+}
+
+fn example_name(day: u8, k: usize) -> String {
+	if k == 1 {
+		format!("example{day}")
+	} else {
+		format!("example{day}-{k}")
+	}
+}
+
+/// Saves each example as the next free `exampleN-K.txt`, unless a file in `dir` already has the
+/// same contents. Examples that were written by hand keep their names that way, and fetching
+/// again doesn't make copies.
+pub fn save_examples(
+	dir: &Path,
+	day: u8,
+	examples: &[Example],
+) -> Result<Vec<SavedExample>, String> {
+	let mut existing: Vec<(String, String)> = Vec::new();
+	let mut k = 1;
+	let mut next_free = || loop {
+		let name = example_name(day, k);
+		k += 1;
+		if !dir.join(format!("{name}.txt")).exists() {
+			return name;
+		}
+	};
+
+	if let Ok(entries) = fs::read_dir(dir) {
+		for entry in entries.flatten() {
+			let file_name = entry.file_name().to_string_lossy().into_owned();
+			let Some(name) = file_name.strip_suffix(".txt") else {
+				continue;
+			};
+			let is_day = name
+				.strip_prefix(&format!("example{day}"))
+				.is_some_and(|k| k.is_empty() || k.starts_with('-'));
+			if let (true, Ok(contents)) = (is_day, fs::read_to_string(entry.path())) {
+				existing.push((name.to_string(), contents));
+			}
+		}
+	}
+
+	let mut saved = Vec::new();
+	for example in examples {
+		let found = existing
+			.iter()
+			.find(|(_, contents)| *contents == example.input);
+		let (name, outcome) = match found {
+			Some((name, _)) => (name.clone(), Outcome::Cached),
+			None => {
+				let name = next_free();
+				write(&dir.join(format!("{name}.txt")), &example.input)?;
+				existing.push((name.clone(), example.input.clone()));
+				(name, Outcome::Saved)
+			},
+		};
+		saved.push(SavedExample {
+			name,
+			outcome,
+			example: example.clone(),
+		});
+	}
+	Ok(saved)
+}
+
+/// Downloads whatever isn't already saved for `day`. The puzzle page gains part 2 once part 1 is
+/// solved, so `refresh_page` downloads it again even if it's cached; the input never changes.
+pub fn fetch_day(
+	client: &Client,
+	paths: &Paths,
+	day: u8,
+	refresh_page: bool,
+) -> Result<Summary, String> {
+	let (page, page_outcome) = if paths.page.exists() && !refresh_page {
+		let page = fs::read_to_string(&paths.page)
+			.map_err(|e| format!("couldn't read {}: {e}", paths.page.display()))?;
+		(page, Outcome::Cached)
+	} else {
+		let page = client.puzzle_page(day)?;
+		write(&paths.page, &page)?;
+		(page, Outcome::Saved)
+	};
+
+	let examples = save_examples(&paths.examples, day, &puzzle::examples(&page))?;
+
+	let input = if paths.input.exists() {
+		Outcome::Cached
+	} else {
+		write(&paths.input, &client.input(day)?)?;
+		Outcome::Saved
+	};
+
+	Ok(Summary {
+		page: page_outcome,
+		input,
+		examples,
+	})
+}
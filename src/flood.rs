@@ -0,0 +1,129 @@
+//! Flood fills and connected regions on a `Grid`. Which moves are allowed is up to the caller,
+//! by a function given the position being left and the one being entered, so the same searches
+//! work for open floor, for pipes that only connect in some directions, and so on.
+
+use std::collections::VecDeque;
+
+use crate::grid::{Grid, NEIGHBORS4, NEIGHBORS8};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+	/// moves to the cells sharing an edge
+	Four,
+	/// moves to the cells sharing an edge or a corner
+	Eight,
+}
+
+impl Connectivity {
+	fn offsets(self) -> &'static [(isize, isize)] {
+		match self {
+			Connectivity::Four => &NEIGHBORS4,
+			Connectivity::Eight => &NEIGHBORS8,
+		}
+	}
+}
+
+/// The cells a flood fill reached.
+#[derive(Debug, Clone)]
+pub struct Fill {
+	/// how many moves it took to reach each cell from the nearest start, if it was reached
+	pub distances: Grid<Option<usize>>,
+	/// how many cells were reached, starts included
+	pub size: usize,
+}
+
+impl Fill {
+	pub fn contains(&self, position: (usize, usize)) -> bool {
+		self.distances[position].is_some()
+	}
+
+	/// The most moves it took to reach any cell.
+	pub fn max_distance(&self) -> Option<usize> {
+		self.distances.iter().filter_map(|(_, &d)| d).max()
+	}
+}
+
+/// Fills outwards from `starts`, breadth first, making every move `can_move(from, to)` allows.
+pub fn fill<T>(
+	grid: &Grid<T>,
+	starts: impl IntoIterator<Item = (usize, usize)>,
+	connectivity: Connectivity,
+	mut can_move: impl FnMut((usize, usize), (usize, usize)) -> bool,
+) -> Fill {
+	let mut distances = Grid::new(grid.width(), grid.height(), None);
+	let mut size = 0;
+	let mut queue = VecDeque::new();
+	for start in starts {
+		if distances[start].is_none() {
+			distances[start] = Some(0);
+			size += 1;
+			queue.push_back(start);
+		}
+	}
+
+	while let Some(position) = queue.pop_front() {
+		let distance = distances[position].unwrap();
+		for &offset in connectivity.offsets() {
+			let Some(next) = grid.step(position, offset) else {
+				continue;
+			};
+			// marking cells as they're queued means each one is only queued once
+			if distances[next].is_none() && can_move(position, next) {
+				distances[next] = Some(distance + 1);
+				size += 1;
+				queue.push_back(next);
+			}
+		}
+	}
+
+	Fill { distances, size }
+}
+
+/// Every cell split into regions, where two neighbouring cells are in the same region if
+/// `can_move` allows a move between them. `can_move` should allow a move both ways or neither.
+#[derive(Debug, Clone)]
+pub struct Components {
+	/// which region each cell is in, numbered in the order their first cells are read
+	pub labels: Grid<usize>,
+	/// how many cells are in each region
+	pub sizes: Vec<usize>,
+}
+
+pub fn components<T>(
+	grid: &Grid<T>,
+	connectivity: Connectivity,
+	mut can_move: impl FnMut((usize, usize), (usize, usize)) -> bool,
+) -> Components {
+	let mut labels: Grid<Option<usize>> = Grid::new(grid.width(), grid.height(), None);
+	let mut sizes = Vec::new();
+	let mut queue = VecDeque::new();
+
+	for start in grid.positions() {
+		if labels[start].is_some() {
+			continue;
+		}
+		let label = sizes.len();
+		labels[start] = Some(label);
+		let mut size = 1;
+		queue.push_back(start);
+
+		while let Some(position) = queue.pop_front() {
+			for &offset in connectivity.offsets() {
+				let Some(next) = grid.step(position, offset) else {
+					continue;
+				};
+				if labels[next].is_none() && can_move(position, next) {
+					labels[next] = Some(label);
+					size += 1;
+					queue.push_back(next);
+				}
+			}
+		}
+		sizes.push(size);
+	}
+
+	Components {
+		labels: labels.map(|l| l.unwrap()),
+		sizes,
+	}
+}
@@ -0,0 +1,185 @@
+//! Points and the four directions you can move in on a grid. `y` grows downwards, the way
+//! puzzle inputs are read, so `Up` is a step towards `y = 0`.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T> Point<T> {
+	pub const fn new(x: T, y: T) -> Point<T> {
+		Point { x, y }
+	}
+}
+
+impl<T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd> Point<T> {
+	pub fn manhattan(self, other: Point<T>) -> T {
+		let dx = if self.x > other.x {
+			self.x - other.x
+		} else {
+			other.x - self.x
+		};
+		let dy = if self.y > other.y {
+			self.y - other.y
+		} else {
+			other.y - self.y
+		};
+		dx + dy
+	}
+}
+
+impl Point<usize> {
+	/// One step in `direction`, if that stays inside a `width` by `height` area.
+	pub fn step(self, direction: Direction, width: usize, height: usize) -> Option<Point<usize>> {
+		let offset: Point<isize> = direction.offset();
+		let x = self.x.checked_add_signed(offset.x).filter(|&x| x < width)?;
+		let y = self
+			.y
+			.checked_add_signed(offset.y)
+			.filter(|&y| y < height)?;
+		Some(Point { x, y })
+	}
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+	type Output = Point<T>;
+
+	fn add(self, other: Point<T>) -> Point<T> {
+		Point {
+			x: self.x + other.x,
+			y: self.y + other.y,
+		}
+	}
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+	type Output = Point<T>;
+
+	fn sub(self, other: Point<T>) -> Point<T> {
+		Point {
+			x: self.x - other.x,
+			y: self.y - other.y,
+		}
+	}
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+	type Output = Point<T>;
+
+	fn mul(self, factor: T) -> Point<T> {
+		Point {
+			x: self.x * factor,
+			y: self.y * factor,
+		}
+	}
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+	type Output = Point<T>;
+
+	fn neg(self) -> Point<T> {
+		Point {
+			x: -self.x,
+			y: -self.y,
+		}
+	}
+}
+
+impl<T> From<(T, T)> for Point<T> {
+	fn from((x, y): (T, T)) -> Self {
+		Point { x, y }
+	}
+}
+
+impl<T> From<Point<T>> for (T, T) {
+	fn from(point: Point<T>) -> Self {
+		(point.x, point.y)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+	Up,
+	Right,
+	Down,
+	Left,
+}
+
+use Direction::*;
+
+impl Direction {
+	/// Clockwise, starting from `Up`.
+	pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+	pub fn turn_right(self) -> Direction {
+		Direction::ALL[(self as usize + 1) % 4]
+	}
+
+	pub fn turn_left(self) -> Direction {
+		Direction::ALL[(self as usize + 3) % 4]
+	}
+
+	pub fn reverse(self) -> Direction {
+		Direction::ALL[(self as usize + 2) % 4]
+	}
+
+	pub fn is_horizontal(self) -> bool {
+		matches!(self, Left | Right)
+	}
+
+	/// The two directions at right angles to this one.
+	pub fn turns(self) -> [Direction; 2] {
+		[self.turn_left(), self.turn_right()]
+	}
+
+	/// The change in position from one step in this direction.
+	pub fn offset<T: From<i8>>(self) -> Point<T> {
+		let (x, y): (i8, i8) = match self {
+			Up => (0, -1),
+			Right => (1, 0),
+			Down => (0, 1),
+			Left => (-1, 0),
+		};
+		Point {
+			x: x.into(),
+			y: y.into(),
+		}
+	}
+
+	/// A distinct bit for each direction, so a set of them fits in a `u8`.
+	pub fn bit(self) -> u8 {
+		1 << self as u8
+	}
+
+	/// The directions whose bits are set in `mask`.
+	pub fn from_mask(mask: u8) -> impl Iterator<Item = Direction> {
+		Direction::ALL
+			.into_iter()
+			.filter(move |d| mask & d.bit() != 0)
+	}
+
+	/// Parses `U`, `D`, `L` or `R`.
+	pub fn from_letter(c: char) -> Option<Direction> {
+		match c {
+			'U' => Some(Up),
+			'R' => Some(Right),
+			'D' => Some(Down),
+			'L' => Some(Left),
+			_ => None,
+		}
+	}
+
+	/// Parses `0` to `3`, meaning right, down, left and up.
+	pub fn from_digit(c: char) -> Option<Direction> {
+		match c {
+			'0' => Some(Right),
+			'1' => Some(Down),
+			'2' => Some(Left),
+			'3' => Some(Up),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,341 @@
+//! A rectangular grid of cells, the shape most puzzle inputs come in. Cells are stored row by
+//! row in one `Vec`, positions are `(x, y)` with `(0, 0)` in the top left corner, and lookups
+//! with signed coordinates return `None` outside the grid instead of wrapping or panicking.
+
+use std::{
+	fmt::Display,
+	ops::{Index, IndexMut},
+};
+
+use crate::{
+	geometry::Point,
+	parse::{self, ParseError},
+};
+
+/// Offsets to the cells sharing an edge, in reading order.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the cells sharing an edge or a corner, in reading order.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+	(-1, -1),
+	(0, -1),
+	(1, -1),
+	(-1, 0),
+	(1, 0),
+	(-1, 1),
+	(0, 1),
+	(1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid {
+			width,
+			height,
+			cells: vec![fill; width * height],
+		}
+	}
+
+	/// A grid where each cell is `f(x, y)`.
+	pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+		let cells = (0..height)
+			.flat_map(|y| (0..width).map(move |x| (x, y)))
+			.map(|(x, y)| f(x, y))
+			.collect();
+		Grid {
+			width,
+			height,
+			cells,
+		}
+	}
+
+	/// Parses `lines`, which are slices of `input`, one cell per character. Characters that
+	/// `T` can't be made from are reported as not being one of `expected`. A grid has to have
+	/// at least one row and one column.
+	pub fn parse<'a>(
+		input: &str,
+		lines: impl IntoIterator<Item = &'a str>,
+		expected: &str,
+	) -> Result<Grid<T>, ParseError>
+	where
+		T: TryFrom<char>,
+	{
+		Grid::parse_with(input, lines, expected, |c| T::try_from(c).ok())
+	}
+
+	/// Like `parse`, but each character is converted with `convert`. Every row has to be as long
+	/// as the first.
+	pub fn parse_with<'a>(
+		input: &str,
+		lines: impl IntoIterator<Item = &'a str>,
+		expected: &str,
+		convert: impl Fn(char) -> Option<T>,
+	) -> Result<Grid<T>, ParseError> {
+		let mut width = None;
+		let mut height = 0;
+		let mut cells = Vec::new();
+		for line in lines {
+			let row = parse::chars(input, line, expected, &convert)?;
+			match width {
+				None if row.is_empty() => {
+					return Err(ParseError::at(input, line, format!("a row of {expected}")));
+				},
+				None => width = Some(row.len()),
+				Some(width) if width != row.len() => {
+					return Err(ParseError::at(
+						input,
+						line,
+						format!("a row of {width} characters"),
+					));
+				},
+				Some(_) => {},
+			}
+			cells.extend(row);
+			height += 1;
+		}
+		let Some(width) = width else {
+			return Err(ParseError::end(input, "a grid"));
+		};
+		Ok(Grid {
+			width,
+			height,
+			cells,
+		})
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn in_bounds(&self, x: isize, y: isize) -> bool {
+		x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+	}
+
+	/// The cell at `(x, y)`, or `None` if that's outside the grid.
+	pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+		if self.in_bounds(x, y) {
+			Some(&self.cells[y as usize * self.width + x as usize])
+		} else {
+			None
+		}
+	}
+
+	pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+		if self.in_bounds(x, y) {
+			Some(&mut self.cells[y as usize * self.width + x as usize])
+		} else {
+			None
+		}
+	}
+
+	/// The position `offset` away from `(x, y)`, if it's still inside the grid.
+	pub fn step(
+		&self,
+		(x, y): (usize, usize),
+		(off_x, off_y): (isize, isize),
+	) -> Option<(usize, usize)> {
+		let (new_x, new_y) = (x as isize + off_x, y as isize + off_y);
+		if self.in_bounds(new_x, new_y) {
+			Some((new_x as usize, new_y as usize))
+		} else {
+			None
+		}
+	}
+
+	/// The positions sharing an edge with `position` that are inside the grid.
+	pub fn neighbors4(
+		&self,
+		position: (usize, usize),
+	) -> impl Iterator<Item = (usize, usize)> + '_ {
+		NEIGHBORS4
+			.iter()
+			.filter_map(move |&offset| self.step(position, offset))
+	}
+
+	/// The positions sharing an edge or a corner with `position` that are inside the grid.
+	pub fn neighbors8(
+		&self,
+		position: (usize, usize),
+	) -> impl Iterator<Item = (usize, usize)> + '_ {
+		NEIGHBORS8
+			.iter()
+			.filter_map(move |&offset| self.step(position, offset))
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		&self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+		&mut self.cells[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		(0..self.height).map(|y| self.row(y))
+	}
+
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+		assert!(
+			x < self.width,
+			"column {x} is outside a grid {} wide",
+			self.width
+		);
+		self.cells[x..].iter().step_by(self.width)
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		(0..self.width).map(|x| self.column(x))
+	}
+
+	/// Every position in reading order.
+	pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+		let width = self.width;
+		(0..self.cells.len()).map(move |i| (i % width, i / width))
+	}
+
+	/// Every cell with its position, in reading order.
+	pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+		self.positions().zip(self.cells.iter())
+	}
+
+	/// The position of the first cell, in reading order, that `predicate` accepts.
+	pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+		self.cells
+			.iter()
+			.position(predicate)
+			.map(|i| (i % self.width, i / self.width))
+	}
+
+	pub fn fill(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.cells.fill(value);
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid {
+			width: self.width,
+			height: self.height,
+			cells: self.cells.iter().map(f).collect(),
+		}
+	}
+
+	/// Swaps rows and columns, so `(x, y)` moves to `(y, x)`.
+	pub fn transpose(&self) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+	}
+
+	/// Turns the grid a quarter turn clockwise, so the left column becomes the top row.
+	pub fn rotate_cw(&self) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.height, self.width, |x, y| {
+			self[(y, self.height - 1 - x)].clone()
+		})
+	}
+
+	/// Turns the grid a quarter turn counterclockwise, so the top row becomes the left column.
+	pub fn rotate_ccw(&self) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.height, self.width, |x, y| {
+			self[(self.width - 1 - y, x)].clone()
+		})
+	}
+
+	/// Mirrors the grid left to right.
+	pub fn flip_horizontal(&self) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.width, self.height, |x, y| {
+			self[(self.width - 1 - x, y)].clone()
+		})
+	}
+
+	/// Mirrors the grid top to bottom.
+	pub fn flip_vertical(&self) -> Grid<T>
+	where
+		T: Clone,
+	{
+		Grid::from_fn(self.width, self.height, |x, y| {
+			self[(x, self.height - 1 - y)].clone()
+		})
+	}
+
+	/// Draws the grid one character per cell, with a newline after each row.
+	pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+		let mut out = String::with_capacity((self.width + 1) * self.height);
+		for row in self.rows() {
+			out.extend(row.iter().map(&to_char));
+			out.push('\n');
+		}
+		out
+	}
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		assert!(
+			x < self.width && y < self.height,
+			"({x}, {y}) is outside a {}x{} grid",
+			self.width,
+			self.height
+		);
+		&self.cells[y * self.width + x]
+	}
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+	fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+		assert!(
+			x < self.width && y < self.height,
+			"({x}, {y}) is outside a {}x{} grid",
+			self.width,
+			self.height
+		);
+		&mut self.cells[y * self.width + x]
+	}
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, point: Point<usize>) -> &T {
+		&self[(point.x, point.y)]
+	}
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+	fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+		&mut self[(point.x, point.y)]
+	}
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.render(|&cell| cell.into()))
+	}
+}
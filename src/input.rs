@@ -0,0 +1,100 @@
+//! Cleaning up puzzle input before it's parsed. Inputs saved by a browser or an editor can pick
+//! up a byte order mark, CRLF line endings, trailing spaces or a missing final newline, and the
+//! parsers shouldn't each have to cope with that.
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+	RemovedBom,
+	/// CRLF or lone CR line endings were turned into LF
+	ConvertedLineEndings,
+	/// trailing whitespace was removed from this many lines
+	TrimmedLines(usize),
+	/// this many blank lines were removed from the end
+	RemovedBlankLines(usize),
+	AddedFinalNewline,
+}
+
+impl Display for Change {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Change::RemovedBom => write!(f, "removed a byte order mark"),
+			Change::ConvertedLineEndings => write!(f, "converted line endings to LF"),
+			Change::TrimmedLines(n) => write!(f, "removed trailing whitespace from {n} lines"),
+			Change::RemovedBlankLines(n) => write!(f, "removed {n} blank lines from the end"),
+			Change::AddedFinalNewline => write!(f, "added a final newline"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+	pub text: String,
+	/// what had to be fixed, empty if the input was already clean
+	pub changes: Vec<Change>,
+}
+
+impl Normalized {
+	/// A one-line description of the changes, for warning about them.
+	pub fn summary(&self) -> Option<String> {
+		if self.changes.is_empty() {
+			return None;
+		}
+		let changes: Vec<String> = self.changes.iter().map(|c| c.to_string()).collect();
+		Some(changes.join(", "))
+	}
+}
+
+/// Strips a byte order mark, converts line endings to LF, removes trailing whitespace from each
+/// line and blank lines from the end, and makes sure the input ends with exactly one newline.
+/// Empty input stays empty.
+pub fn normalize(raw: &str) -> Normalized {
+	let mut changes = Vec::new();
+
+	let without_bom = match raw.strip_prefix('\u{feff}') {
+		Some(rest) => {
+			changes.push(Change::RemovedBom);
+			rest
+		},
+		None => raw,
+	};
+
+	let unified;
+	let text = if without_bom.contains('\r') {
+		changes.push(Change::ConvertedLineEndings);
+		unified = without_bom.replace("\r\n", "\n").replace('\r', "\n");
+		unified.as_str()
+	} else {
+		without_bom
+	};
+
+	let mut lines: Vec<&str> = text.lines().collect();
+	let mut trimmed = 0;
+	for line in lines.iter_mut() {
+		let trimmed_line = line.trim_end();
+		if trimmed_line.len() != line.len() {
+			trimmed += 1;
+			*line = trimmed_line;
+		}
+	}
+	if trimmed > 0 {
+		changes.push(Change::TrimmedLines(trimmed));
+	}
+
+	let blank = lines.iter().rev().take_while(|l| l.is_empty()).count();
+	if blank > 0 {
+		lines.truncate(lines.len() - blank);
+		changes.push(Change::RemovedBlankLines(blank));
+	}
+
+	if blank == 0 && !lines.is_empty() && !text.ends_with('\n') {
+		changes.push(Change::AddedFinalNewline);
+	}
+
+	let mut text = lines.join("\n");
+	if !text.is_empty() {
+		text.push('\n');
+	}
+	Normalized { text, changes }
+}
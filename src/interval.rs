@@ -0,0 +1,271 @@
+//! Ranges of integers and sets of them, for puzzles that push whole ranges of values through a
+//! process instead of one value at a time.
+
+use std::fmt::Debug;
+
+use crate::checked::Checked;
+
+/// The integers from `start` up to but not including `end`. Every interval with `start >= end`
+/// is empty; `Interval::EMPTY` is just the simplest one.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+	pub start: i64,
+	pub end: i64,
+}
+
+impl Interval {
+	pub const EMPTY: Interval = Interval { start: 0, end: 0 };
+
+	pub const fn new(start: i64, end: i64) -> Interval {
+		Interval { start, end }
+	}
+
+	/// The `len` integers starting at `start`.
+	pub fn from_len(start: i64, len: i64) -> Interval {
+		Interval {
+			start,
+			end: (Checked(start) + len).get(),
+		}
+	}
+
+	/// The integers from `start` to `end`, both included.
+	pub fn inclusive(start: i64, end: i64) -> Interval {
+		Interval {
+			start,
+			end: (Checked(end) + 1).get(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.start >= self.end
+	}
+
+	pub fn len(&self) -> i64 {
+		if self.is_empty() {
+			0
+		} else {
+			(Checked(self.end) - self.start).get()
+		}
+	}
+
+	pub fn contains(&self, value: i64) -> bool {
+		self.start <= value && value < self.end
+	}
+
+	pub fn intersect(&self, other: Interval) -> Interval {
+		Interval {
+			start: self.start.max(other.start),
+			end: self.end.min(other.end),
+		}
+	}
+
+	pub fn overlaps(&self, other: Interval) -> bool {
+		!self.intersect(other).is_empty()
+	}
+
+	/// The values below `at`, and the ones from `at` up.
+	pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+		let at = at.clamp(self.start, self.end.max(self.start));
+		(Interval::new(self.start, at), Interval::new(at, self.end))
+	}
+
+	/// The parts of this interval below and above `other`, either of which can be empty.
+	pub fn difference(&self, other: Interval) -> [Interval; 2] {
+		if other.is_empty() {
+			return [*self, Interval::EMPTY];
+		}
+		let (below, _) = self.split_at(other.start);
+		let (_, above) = self.split_at(other.end);
+		[below, above]
+	}
+
+	pub fn shift(&self, by: i64) -> Interval {
+		Interval::new(
+			(Checked(self.start) + by).get(),
+			(Checked(self.end) + by).get(),
+		)
+	}
+}
+
+impl Debug for Interval {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if self.is_empty() {
+			write!(f, "(empty)")
+		} else {
+			write!(f, "{}..{}", self.start, self.end)
+		}
+	}
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+	intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+	pub fn new() -> IntervalSet {
+		IntervalSet::default()
+	}
+
+	pub fn insert(&mut self, interval: Interval) {
+		if interval.is_empty() {
+			return;
+		}
+		// everything that overlaps or touches the new interval gets merged into it
+		let first = self.intervals.partition_point(|i| i.end < interval.start);
+		let last = self.intervals.partition_point(|i| i.start <= interval.end);
+		let mut merged = interval;
+		if first < last {
+			merged.start = merged.start.min(self.intervals[first].start);
+			merged.end = merged.end.max(self.intervals[last - 1].end);
+		}
+		self.intervals.splice(first..last, [merged]);
+	}
+
+	pub fn remove(&mut self, interval: Interval) {
+		if interval.is_empty() {
+			return;
+		}
+		let first = self.intervals.partition_point(|i| i.end <= interval.start);
+		let last = self.intervals.partition_point(|i| i.start < interval.end);
+		let remaining: Vec<Interval> = self.intervals[first..last]
+			.iter()
+			.flat_map(|i| i.difference(interval))
+			.filter(|i| !i.is_empty())
+			.collect();
+		self.intervals.splice(first..last, remaining);
+	}
+
+	pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+		let mut set = self.clone();
+		for &interval in &other.intervals {
+			set.insert(interval);
+		}
+		set
+	}
+
+	pub fn intersect(&self, other: &IntervalSet) -> IntervalSet {
+		self.intervals
+			.iter()
+			.flat_map(|a| other.intervals.iter().map(|b| a.intersect(*b)))
+			.collect()
+	}
+
+	pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+		let mut set = self.clone();
+		for &interval in &other.intervals {
+			set.remove(interval);
+		}
+		set
+	}
+
+	/// The values in this set that are also in `interval`.
+	pub fn intersect_interval(&self, interval: Interval) -> IntervalSet {
+		self.intervals
+			.iter()
+			.map(|i| i.intersect(interval))
+			.collect()
+	}
+
+	pub fn shift(&self, by: i64) -> IntervalSet {
+		IntervalSet {
+			intervals: self.intervals.iter().map(|i| i.shift(by)).collect(),
+		}
+	}
+
+	pub fn contains(&self, value: i64) -> bool {
+		let i = self.intervals.partition_point(|i| i.end <= value);
+		self.intervals.get(i).is_some_and(|i| i.contains(value))
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.intervals.is_empty()
+	}
+
+	/// How many values are in the set.
+	pub fn len(&self) -> i64 {
+		self.intervals
+			.iter()
+			.fold(Checked(0), |len, i| len + i.len())
+			.get()
+	}
+
+	pub fn min(&self) -> Option<i64> {
+		self.intervals.first().map(|i| i.start)
+	}
+
+	pub fn max(&self) -> Option<i64> {
+		self.intervals.last().map(|i| i.end - 1)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+		self.intervals.iter().copied()
+	}
+}
+
+impl FromIterator<Interval> for IntervalSet {
+	fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+		let mut set = IntervalSet::new();
+		for interval in iter {
+			set.insert(interval);
+		}
+		set
+	}
+}
+
+impl Debug for IntervalSet {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_set().entries(&self.intervals).finish()
+	}
+}
+
+/// A box in `N` dimensions: an interval along each axis. It's empty if any of them is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalBox<const N: usize> {
+	pub axes: [Interval; N],
+}
+
+impl<const N: usize> IntervalBox<N> {
+	pub const fn new(axes: [Interval; N]) -> IntervalBox<N> {
+		IntervalBox { axes }
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.axes.iter().any(|a| a.is_empty())
+	}
+
+	/// How many integer points are inside the box.
+	pub fn volume(&self) -> i64 {
+		if self.is_empty() {
+			return 0;
+		}
+		self.axes
+			.iter()
+			.fold(Checked(1), |volume, a| volume * a.len())
+			.get()
+	}
+
+	pub fn contains(&self, point: [i64; N]) -> bool {
+		self.axes.iter().zip(point).all(|(a, p)| a.contains(p))
+	}
+
+	pub fn intersect(&self, other: &IntervalBox<N>) -> IntervalBox<N> {
+		IntervalBox {
+			axes: std::array::from_fn(|i| self.axes[i].intersect(other.axes[i])),
+		}
+	}
+
+	/// This box with its interval along `axis` replaced.
+	pub fn with_axis(&self, axis: usize, interval: Interval) -> IntervalBox<N> {
+		let mut axes = self.axes;
+		axes[axis] = interval;
+		IntervalBox { axes }
+	}
+
+	/// The parts of the box below `at` and from `at` up along `axis`.
+	pub fn split_at(&self, axis: usize, at: i64) -> (IntervalBox<N>, IntervalBox<N>) {
+		let (below, above) = self.axes[axis].split_at(at);
+		(self.with_axis(axis, below), self.with_axis(axis, above))
+	}
+}
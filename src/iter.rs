@@ -0,0 +1,86 @@
+//! Adapters that hand out items a fixed number at a time as arrays, so they can be
+//! destructured straight into names. The standard library's own versions are nightly-only;
+//! these work on stable.
+
+/// Array versions of iterator adapters, for every iterator.
+pub trait IterExt: Iterator + Sized {
+	/// The next `N` items, or `None` if there are fewer than `N` left, in which case whatever
+	/// was left has been used up.
+	fn next_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+		let mut done = false;
+		let items: [Option<Self::Item>; N] = std::array::from_fn(|_| {
+			let item = if done { None } else { self.next() };
+			done = item.is_none();
+			item
+		});
+		if done {
+			return None;
+		}
+		Some(items.map(Option::unwrap))
+	}
+
+	/// All of the items, if there are exactly `N`.
+	fn collect_array<const N: usize>(mut self) -> Option<[Self::Item; N]> {
+		let items = self.next_array()?;
+		self.next().is_none().then_some(items)
+	}
+
+	/// The items `N` at a time, leaving out any left over at the end. This is `array_chunks`
+	/// under another name: `Iterator::array_chunks` exists on nightly, and calling a trait
+	/// method with the same name as an unstable std one warns at every call site on stable
+	/// (`unstable_name_collisions`), and would quietly switch to the std one once it's stable.
+	fn arrays<const N: usize>(self) -> Arrays<Self, N> {
+		assert!(N > 0, "chunks can't be empty");
+		Arrays { iter: self }
+	}
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/// The iterator returned by `IterExt::arrays`.
+#[derive(Debug, Clone)]
+pub struct Arrays<I, const N: usize> {
+	iter: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for Arrays<I, N> {
+	type Item = [I::Item; N];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.next_array()
+	}
+}
+
+/// Array versions of slice adapters. Slices have unstable methods with these names on older
+/// toolchains, and calling a trait method whose name collides with an unstable one warns
+/// (`unstable_name_collisions`), so call these through the trait:
+/// `SliceExt::array_windows::<2>(slice)`.
+pub trait SliceExt<T> {
+	/// The slice `N` elements at a time, leaving out any left over at the end.
+	fn array_chunks<'a, const N: usize>(&'a self) -> impl Iterator<Item = &'a [T; N]>
+	where
+		T: 'a;
+
+	/// Every run of `N` consecutive elements, overlapping, from the start of the slice on.
+	fn array_windows<'a, const N: usize>(&'a self) -> impl Iterator<Item = &'a [T; N]>
+	where
+		T: 'a;
+}
+
+impl<T> SliceExt<T> for [T] {
+	fn array_chunks<'a, const N: usize>(&'a self) -> impl Iterator<Item = &'a [T; N]>
+	where
+		T: 'a,
+	{
+		assert!(N > 0, "chunks can't be empty");
+		self.chunks_exact(N).map(|chunk| chunk.try_into().unwrap())
+	}
+
+	fn array_windows<'a, const N: usize>(&'a self) -> impl Iterator<Item = &'a [T; N]>
+	where
+		T: 'a,
+	{
+		assert!(N > 0, "windows can't be empty");
+		self.windows(N).map(|window| window.try_into().unwrap())
+	}
+}
@@ -0,0 +1,284 @@
+use std::{
+	fmt::Display,
+	io::{self, Read},
+	path::PathBuf,
+	process,
+	str::FromStr,
+	time::Instant,
+};
+
+use output::Report;
+use params::{Param, Params};
+use parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod bits;
+pub mod checked;
+pub mod cycle;
+pub mod days;
+pub mod fetch;
+pub mod flood;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod iter;
+pub mod math;
+pub mod memo;
+pub mod output;
+pub mod params;
+pub mod parse;
+pub mod pathfinding;
+pub mod polygon;
+pub mod puzzle;
+pub mod scaffold;
+pub mod submit;
+
+/// The answer to one part of a puzzle. Most puzzles want a number, but some want text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+	Number(i128),
+	Text(String),
+}
+
+macro_rules! answer_from_int {
+	($($t:ty),*) => {
+		$(
+			impl From<$t> for Answer {
+				fn from(value: $t) -> Self {
+					Answer::Number(value as i128)
+				}
+			}
+		)*
+	};
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+	fn from(value: String) -> Self {
+		Answer::Text(value)
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(value: &str) -> Self {
+		Answer::Text(value.to_string())
+	}
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Answer::Number(n) => write!(f, "{n}"),
+			Answer::Text(s) => write!(f, "{s}"),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+	One,
+	Two,
+}
+
+impl Display for Part {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Part::One => write!(f, "1"),
+			Part::Two => write!(f, "2"),
+		}
+	}
+}
+
+impl FromStr for Part {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"1" => Ok(Part::One),
+			"2" => Ok(Part::Two),
+			_ => Err(format!("invalid part {s:?}, expected 1 or 2")),
+		}
+	}
+}
+
+/// One day's puzzle. The input is parsed once into `Self`, and both parts are answered from
+/// that parsed model.
+pub trait Solution: Sized {
+	/// Whether `part2` is implemented, so the runner knows without having an input to try.
+	const HAS_PART2: bool = true;
+
+	/// The parameters the solver takes from the puzzle text, with the puzzle's values as
+	/// defaults.
+	const PARAMS: &'static [Param] = &[];
+
+	fn parse(input: &str) -> Result<Self, ParseError>;
+
+	/// Rejects combinations of parameters the solver can't work with, like a minimum above a
+	/// maximum. Each value is already known to be in its declared range.
+	fn check_params(_params: &Params) -> Result<(), String> {
+		Ok(())
+	}
+
+	/// Takes the values of `PARAMS` to use. The runner calls this after every parse, with the
+	/// defaults if nothing was overridden.
+	fn configure(&mut self, _params: &Params) {}
+
+	/// Rejects an input that parsed but that the parts can't answer with the configured
+	/// parameters, like a maze with no way through. The runner calls this after `configure`.
+	fn check_input(&self, _input: &str) -> Result<(), ParseError> {
+		Ok(())
+	}
+
+	fn part1(&self) -> Answer;
+
+	/// Days whose second part hasn't been solved yet keep the default, and set `HAS_PART2` to
+	/// false.
+	fn part2(&self) -> Option<Answer> {
+		None
+	}
+}
+
+/// Object-safe view of a parsed `Solution`, so that every day can sit in the same table.
+pub trait ParsedSolution {
+	fn part1(&self) -> Answer;
+	fn part2(&self) -> Option<Answer>;
+}
+
+impl<S: Solution> ParsedSolution for S {
+	fn part1(&self) -> Answer {
+		Solution::part1(self)
+	}
+
+	fn part2(&self) -> Option<Answer> {
+		Solution::part2(self)
+	}
+}
+
+/// Parses a day's input and configures it with the given parameters.
+type ParseFn = fn(&str, &Params) -> Result<Box<dyn ParsedSolution>, ParseError>;
+
+/// An entry in the table of implemented days, `days::ALL`.
+pub struct Day {
+	pub number: u8,
+	pub has_part2: bool,
+	pub params: &'static [Param],
+	check_params: fn(&Params) -> Result<(), String>,
+	parse: ParseFn,
+}
+
+impl Day {
+	pub const fn new<S: Solution + 'static>(number: u8) -> Day {
+		Day {
+			number,
+			has_part2: S::HAS_PART2,
+			params: S::PARAMS,
+			check_params: S::check_params,
+			parse: |input, params| {
+				let mut solution = S::parse(input)?;
+				solution.configure(params);
+				solution.check_input(input)?;
+				Ok(Box::new(solution))
+			},
+		}
+	}
+
+	pub fn find(number: u8) -> Option<&'static Day> {
+		days::ALL.iter().find(|d| d.number == number)
+	}
+
+	pub fn parts(&self) -> &'static [Part] {
+		if self.has_part2 {
+			&[Part::One, Part::Two]
+		} else {
+			&[Part::One]
+		}
+	}
+
+	/// The day's parameters with `overrides` applied, if they're all valid.
+	pub fn resolve_params(&self, overrides: &[(String, i64)]) -> Result<Params, String> {
+		let params = Params::with_overrides(self.params, overrides)?;
+		(self.check_params)(&params)?;
+		Ok(params)
+	}
+
+	pub fn parse(&self, input: &str) -> Result<Box<dyn ParsedSolution>, ParseError> {
+		self.parse_with(input, &Params::defaults(self.params))
+	}
+
+	/// `parse`, configured with `params`, which should come from `resolve_params`.
+	pub fn parse_with(
+		&self,
+		input: &str,
+		params: &Params,
+	) -> Result<Box<dyn ParsedSolution>, ParseError> {
+		(self.parse)(input, params)
+	}
+
+	/// Parses `input` once and then solves each of `parts`, timing each one.
+	pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Report>, ParseError> {
+		self.solve_with(input, parts, &Params::defaults(self.params))
+	}
+
+	/// `solve`, with `params` in place of the defaults.
+	pub fn solve_with(
+		&self,
+		input: &str,
+		parts: &[Part],
+		params: &Params,
+	) -> Result<Vec<Report>, ParseError> {
+		let solution = self.parse_with(input, params)?;
+		Ok(parts
+			.iter()
+			.map(|&part| {
+				let start = Instant::now();
+				let answer = match part {
+					Part::One => solution.part1(),
+					Part::Two => solution.part2().expect("part 2 is not solved yet"),
+				};
+				Report {
+					day: self.number,
+					part,
+					answer,
+					elapsed: start.elapsed(),
+				}
+			})
+			.collect())
+	}
+
+	/// Where `aoc fetch` saves this day's puzzle input.
+	pub fn input_path(&self) -> PathBuf {
+		PathBuf::from(format!("inputs/input{}.txt", self.number))
+	}
+}
+
+/// Reads the puzzle input from stdin and prints the answer to one part. This is all the
+/// per-part binaries do. Input that doesn't parse is reported on stderr, and the process exits
+/// with an error status.
+pub fn run_stdin<S: Solution>(part: Part) -> io::Result<()> {
+	let mut raw = String::new();
+	io::stdin().read_to_string(&mut raw)?;
+	let normalized = input::normalize(&raw);
+	if let Some(summary) = normalized.summary() {
+		eprintln!("warning: stdin: {summary}");
+	}
+	let input = normalized.text;
+
+	let mut solution = S::parse(&input).unwrap_or_else(|e| {
+		eprintln!("{}", e.report(&input));
+		process::exit(1);
+	});
+	solution.configure(&Params::defaults(S::PARAMS));
+	if let Err(e) = solution.check_input(&input) {
+		eprintln!("{}", e.report(&input));
+		process::exit(1);
+	}
+	let answer = match part {
+		Part::One => solution.part1(),
+		Part::Two => solution.part2().expect("part 2 is not solved yet"),
+	};
+
+	println!("{answer}");
+	Ok(())
+}
@@ -0,0 +1,90 @@
+//! Number theory that keeps coming up when puzzles are about things lining up: common multiples
+//! of loop lengths, modular inverses and systems of congruences. Anything that could overflow
+//! returns `None` instead.
+
+/// The greatest common divisor. `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+	while b != 0 {
+		let t = b;
+		b = a % b;
+		a = t;
+	}
+	a
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`. It's 0 if either is.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+	if a == 0 || b == 0 {
+		return Some(0);
+	}
+	// dividing first keeps the intermediate value no bigger than the result
+	(a / gcd(a, b)).checked_mul(b)
+}
+
+/// The least common multiple of all of `values`, which is 1 if there aren't any.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+	values.into_iter().try_fold(1, lcm)
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` where `g` is the non-negative gcd of `a` and
+/// `b`, and `a * x + b * y == g`. `None` if any of them doesn't fit in an `i64`, which can only
+/// happen when `a` or `b` is `i64::MIN`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+	let (mut old_r, mut r) = (a as i128, b as i128);
+	let (mut old_x, mut x) = (1i128, 0i128);
+	let (mut old_y, mut y) = (0i128, 1i128);
+	while r != 0 {
+		let q = old_r / r;
+		(old_r, r) = (r, old_r - q * r);
+		(old_x, x) = (x, old_x - q * x);
+		(old_y, y) = (y, old_y - q * y);
+	}
+	if old_r < 0 {
+		(old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+	}
+	Some((
+		i64::try_from(old_r).ok()?,
+		i64::try_from(old_x).ok()?,
+		i64::try_from(old_y).ok()?,
+	))
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+	assert!(modulus > 0, "modulus {modulus} isn't positive");
+	let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+	(g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, given as `(residue, modulus)`
+/// pairs. The moduli don't have to be coprime. Returns the smallest non-negative solution and
+/// the modulus it repeats with (the lcm of all the moduli), or `None` if the congruences
+/// contradict each other or the lcm doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+	let (mut x, mut m) = (0i128, 1i128);
+	for (residue, modulus) in congruences {
+		assert!(modulus > 0, "modulus {modulus} isn't positive");
+		let (residue, modulus) = (
+			(residue as i128).rem_euclid(modulus as i128),
+			modulus as i128,
+		);
+
+		// x + m * k ≡ residue (mod modulus) has a solution only if the gcd divides the gap
+		let (g, p, _) = extended_gcd(m as i64, modulus as i64)?;
+		let g = g as i128;
+		let gap = residue - x;
+		if gap % g != 0 {
+			return None;
+		}
+		let step = modulus / g;
+		let k = ((gap / g) % step * (p as i128 % step)).rem_euclid(step);
+
+		let new_m = m * step;
+		if new_m > i64::MAX as i128 {
+			return None;
+		}
+		x = (x + m * k).rem_euclid(new_m);
+		m = new_m;
+	}
+	Some((x as i64, m as i64))
+}
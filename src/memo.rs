@@ -0,0 +1,82 @@
+//! Caching for recursive solutions that would otherwise work out the same subproblems over and
+//! over. The cache is passed down through the recursion rather than kept in a static, so each
+//! top-level call starts from a fresh one and nothing leaks between inputs.
+//!
+//! Recursing over the tails of slices is common, and then the lengths of the tails make a
+//! cheap key, since the tail is all that's left to decide.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// How often a `Memo` already had the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+	/// lookups that were answered from the cache
+	pub hits: usize,
+	/// lookups that had to be computed
+	pub misses: usize,
+}
+
+impl Stats {
+	/// The fraction of lookups that were hits, 0 if there haven't been any.
+	pub fn hit_rate(&self) -> f64 {
+		let total = self.hits + self.misses;
+		if total == 0 {
+			0.0
+		} else {
+			self.hits as f64 / total as f64
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+	cache: HashMap<K, V>,
+	stats: Stats,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Memo<K, V> {
+	pub fn new() -> Memo<K, V> {
+		Memo {
+			cache: HashMap::new(),
+			stats: Stats::default(),
+		}
+	}
+
+	/// The value for `key`, worked out with `compute` the first time it's asked for. `compute`
+	/// is given the memo back so that it can look up the subproblems it needs.
+	pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+		if let Some(value) = self.cache.get(&key) {
+			self.stats.hits += 1;
+			return value.clone();
+		}
+		self.stats.misses += 1;
+		let value = compute(self);
+		self.cache.insert(key, value.clone());
+		value
+	}
+
+	pub fn stats(&self) -> Stats {
+		self.stats
+	}
+
+	/// How many values are cached.
+	pub fn len(&self) -> usize {
+		self.cache.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.cache.is_empty()
+	}
+
+	/// Forgets every value and resets the stats.
+	pub fn clear(&mut self) {
+		self.cache.clear();
+		self.stats = Stats::default();
+	}
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for Memo<K, V> {
+	fn default() -> Self {
+		Memo::new()
+	}
+}
@@ -0,0 +1,77 @@
+//! How answers are printed. Text is for people, JSON is for scripts; both go to stdout, one
+//! answer per line, and nothing else does.
+
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+	Text,
+	Json,
+}
+
+impl FromStr for Format {
+	type Err = String;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"text" => Ok(Format::Text),
+			"json" => Ok(Format::Json),
+			_ => Err(format!("invalid format {s:?}, expected text or json")),
+		}
+	}
+}
+
+/// One solved part. `elapsed` covers solving that part, not parsing the input.
+#[derive(Debug, Clone)]
+pub struct Report {
+	pub day: u8,
+	pub part: Part,
+	pub answer: Answer,
+	pub elapsed: Duration,
+}
+
+impl Report {
+	pub fn to_text(&self) -> String {
+		format!("day {} part {}: {}", self.day, self.part, self.answer)
+	}
+
+	/// A single-line JSON object. Numeric answers stay numbers and `elapsed` is in seconds.
+	pub fn to_json(&self) -> String {
+		let answer = match &self.answer {
+			Answer::Number(n) => n.to_string(),
+			Answer::Text(s) => json_string(s),
+		};
+		format!(
+			"{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed\":{}}}",
+			self.day,
+			self.part,
+			answer,
+			self.elapsed.as_secs_f64()
+		)
+	}
+
+	pub fn print(&self, format: Format) {
+		match format {
+			Format::Text => println!("{}", self.to_text()),
+			Format::Json => println!("{}", self.to_json()),
+		}
+	}
+}
+
+fn json_string(s: &str) -> String {
+	let mut out = String::from('"');
+	for c in s.chars() {
+		match c {
+			'"' => out += "\\\"",
+			'\\' => out += "\\\\",
+			'\n' => out += "\\n",
+			'\r' => out += "\\r",
+			'\t' => out += "\\t",
+			c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
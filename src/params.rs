@@ -0,0 +1,149 @@
+//! Numbers a solver takes from the puzzle text rather than from the input, like how many cubes
+//! the bag holds or how many cycles to run. Each solver declares its parameters with defaults
+//! (the puzzle's values), and the runner can override them with `--param name=value` to try
+//! the examples' smaller values or explore variations.
+//!
+//! Overrides are checked against the range each parameter declares, and against any rules the
+//! solver has about how they fit together, before any solving starts. Values that only fail
+//! for some inputs, like straight limits too long for day 17's grid, are rejected by
+//! `Solution::check_input` once the input is parsed. A solver only ever sees values it said it
+//! could handle.
+
+use std::fmt::Display;
+
+/// A parameter a solver declares, in `Solution::PARAMS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+	pub name: &'static str,
+	pub default: i64,
+	/// the smallest value it can be overridden with
+	pub min: i64,
+	/// the largest value it can be overridden with
+	pub max: i64,
+	pub description: &'static str,
+}
+
+impl Param {
+	/// A parameter that can take any value. Most should be narrowed with `range`.
+	pub const fn new(name: &'static str, default: i64, description: &'static str) -> Param {
+		Param {
+			name,
+			default,
+			min: i64::MIN,
+			max: i64::MAX,
+			description,
+		}
+	}
+
+	/// This parameter, limited to values from `min` to `max`, both included. The range should
+	/// fit in the type the solver reads the parameter as.
+	pub const fn range(self, min: i64, max: i64) -> Param {
+		assert!(min <= self.default && self.default <= max);
+		Param { min, max, ..self }
+	}
+
+	/// The allowed values, in the form `min..=max`, or `min..` when there's no real maximum.
+	pub fn range_text(&self) -> String {
+		match (self.min, self.max) {
+			(i64::MIN, i64::MAX) => "..".to_string(),
+			(min, i64::MAX) => format!("{min}.."),
+			(min, max) => format!("{min}..={max}"),
+		}
+	}
+
+	fn check(&self, value: i64) -> Result<(), String> {
+		if (self.min..=self.max).contains(&value) {
+			Ok(())
+		} else {
+			Err(format!(
+				"invalid value {value} for parameter {}, expected {}",
+				self.name,
+				self.range_text()
+			))
+		}
+	}
+}
+
+/// The value of every parameter a solver declared: its default unless it was overridden.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+	values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+	pub fn defaults(declared: &[Param]) -> Params {
+		Params {
+			values: declared.iter().map(|p| (p.name, p.default)).collect(),
+		}
+	}
+
+	/// The defaults of `declared`, with each of `overrides` replacing one of them. Overriding a
+	/// parameter that wasn't declared, or with a value outside its range, is an error.
+	pub fn with_overrides(
+		declared: &[Param],
+		overrides: &[(String, i64)],
+	) -> Result<Params, String> {
+		let mut params = Params::defaults(declared);
+		for (name, value) in overrides {
+			let Some((param, entry)) = declared
+				.iter()
+				.zip(params.values.iter_mut())
+				.find(|(p, _)| p.name == name)
+			else {
+				let names: Vec<&str> = declared.iter().map(|p| p.name).collect();
+				return Err(if names.is_empty() {
+					format!("unknown parameter {name:?}; this day has none")
+				} else {
+					format!(
+						"unknown parameter {name:?}; expected one of {}",
+						names.join(", ")
+					)
+				});
+			};
+			param.check(*value)?;
+			entry.1 = *value;
+		}
+		Ok(params)
+	}
+
+	/// The value of `name`, converted to the type the solver works with. Values have been
+	/// checked against their ranges already, so this only panics if the solver asks for a
+	/// parameter it didn't declare or declared a range its type can't hold.
+	pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+		let &(_, value) = self
+			.values
+			.iter()
+			.find(|(n, _)| *n == name)
+			.unwrap_or_else(|| panic!("parameter {name:?} isn't declared"));
+		T::try_from(value).unwrap_or_else(|_| {
+			panic!(
+				"parameter {name} = {value} doesn't fit in {}",
+				std::any::type_name::<T>()
+			)
+		})
+	}
+}
+
+/// Every value as `name=value`, separated by commas, in the order they were declared.
+impl Display for Params {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (i, (name, value)) in self.values.iter().enumerate() {
+			if i > 0 {
+				write!(f, ",")?;
+			}
+			write!(f, "{name}={value}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Parses a `name=value` override, as given to `--param`.
+pub fn parse_override(arg: &str) -> Result<(String, i64), String> {
+	let (name, value) = arg
+		.split_once('=')
+		.ok_or_else(|| format!("invalid parameter {arg:?}, expected name=value"))?;
+	let value = value
+		.parse()
+		.map_err(|_| format!("invalid value {value:?} for parameter {name}"))?;
+	Ok((name.to_string(), value))
+}
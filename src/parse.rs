@@ -0,0 +1,319 @@
+//! Errors for input that doesn't parse, pointing at where in the input the problem is, and the
+//! helpers the days build their parsers from.
+//!
+//! Parsers work on slices of the input they were given (`lines()`, `split`, and so on), so an
+//! error is built from the offending slice and the position is worked out from where that slice
+//! sits in the input. Every helper here takes the whole input alongside the slice it works on
+//! for that reason, and only ever hands back slices of it.
+
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+	/// 1-based
+	pub line: usize,
+	/// 1-based, counted in characters
+	pub column: usize,
+	/// the input that didn't match; empty at the end of a line or of the input
+	pub text: String,
+	/// what should have been there instead
+	pub expected: String,
+}
+
+impl ParseError {
+	pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> ParseError {
+		ParseError {
+			line,
+			column,
+			text: text.to_string(),
+			expected: expected.into(),
+		}
+	}
+
+	/// An error about `text`, which has to be a slice of `input`.
+	pub fn at(input: &str, text: &str, expected: impl Into<String>) -> ParseError {
+		let offset = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+		assert!(
+			offset <= input.len() && offset + text.len() <= input.len(),
+			"{text:?} is not part of the input"
+		);
+
+		let before = &input[..offset];
+		let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+		ParseError::new(
+			before.matches('\n').count() + 1,
+			before[line_start..].chars().count() + 1,
+			text,
+			expected,
+		)
+	}
+
+	/// An error for input that stopped while `expected` was still missing.
+	pub fn end(input: &str, expected: impl Into<String>) -> ParseError {
+		ParseError::at(input, &input[input.len()..], expected)
+	}
+
+	/// The message, followed by the line of `input` the error is on with the offending text
+	/// underlined:
+	///
+	/// ```text
+	/// line 2, column 9: expected a number, found "x"
+	///   |
+	/// 2 | Game 2: x blue
+	///   |         ^
+	/// ```
+	pub fn report(&self, input: &str) -> String {
+		let source_line = input.lines().nth(self.line - 1).unwrap_or("");
+		let number = self.line.to_string();
+		let gutter = " ".repeat(number.len());
+		let carets = self
+			.text
+			.lines()
+			.next()
+			.map_or(1, |t| t.chars().count().max(1));
+		format!(
+			"{self}\n{gutter} |\n{number} | {source_line}\n{gutter} | {}{}",
+			" ".repeat(self.column - 1),
+			"^".repeat(carets)
+		)
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"line {}, column {}: expected {}, found ",
+			self.line, self.column, self.expected
+		)?;
+		if self.text.is_empty() {
+			write!(f, "nothing")
+		} else {
+			write!(f, "{:?}", self.text)
+		}
+	}
+}
+
+impl Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+	text.parse()
+		.map_err(|_| ParseError::at(input, text, "a number"))
+}
+
+/// Splits `text`, a slice of `input`, around the first `separator`.
+pub fn split_once<'a>(
+	input: &str,
+	text: &'a str,
+	separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+	text.split_once(separator)
+		.ok_or_else(|| ParseError::at(input, text, format!("{separator:?}")))
+}
+
+/// Removes `prefix` from `text`, a slice of `input`.
+pub fn strip_prefix<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+	text.strip_prefix(prefix).ok_or_else(|| {
+		let len = text
+			.char_indices()
+			.map(|(i, c)| i + c.len_utf8())
+			.take_while(|&end| end <= prefix.len())
+			.last()
+			.unwrap_or(0);
+		ParseError::at(input, &text[..len], format!("{prefix:?}"))
+	})
+}
+
+/// Each character of `line`, a slice of `input`, converted with `convert`. Characters it
+/// rejects are reported as not being one of `expected`.
+pub fn chars<T>(
+	input: &str,
+	line: &str,
+	expected: &str,
+	convert: impl Fn(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+	line.char_indices()
+		.map(|(i, c)| {
+			convert(c).ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))
+		})
+		.collect()
+}
+
+/// Each of the whitespace-separated numbers in `text`, a slice of `input`.
+pub fn numbers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+	text.split_ascii_whitespace()
+		.map(|n| number(input, n))
+		.collect()
+}
+
+/// Every integer in `text`, a slice of `input`, ignoring whatever is between them. A `-` or `+`
+/// directly in front of the digits is part of the number, unless it comes straight after a
+/// letter or digit, so `x-5` and the range `1-2` don't have negative numbers in them.
+pub fn extract_numbers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+	let mut numbers = Vec::new();
+	let mut position = 0;
+	while let Some(found) = text[position..].find(|c: char| c.is_ascii_digit()) {
+		let mut start = position + found;
+		let end = text[start..]
+			.find(|c: char| !c.is_ascii_digit())
+			.map_or(text.len(), |len| start + len);
+		let before = &text[..start];
+		if before.ends_with(['-', '+'])
+			&& !before[..start - 1]
+				.chars()
+				.next_back()
+				.is_some_and(char::is_alphanumeric)
+		{
+			start -= 1;
+		}
+		numbers.push(number(input, &text[start..end])?);
+		position = end;
+	}
+	Ok(numbers)
+}
+
+/// Each `separator`-separated item of `text`, parsed with `item`.
+pub fn list<'a, T>(
+	text: &'a str,
+	separator: &str,
+	item: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+	text.split(separator).map(item).collect()
+}
+
+/// The `key`/`value` pairs of `text`, a slice of `input`, with `separator` between the pairs and
+/// `key_separator` between each key and its value, like `a=1,b=2`.
+pub fn key_values<'a>(
+	input: &str,
+	text: &'a str,
+	separator: &str,
+	key_separator: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+	list(text, separator, |pair| {
+		split_once(input, pair, key_separator)
+	})
+}
+
+/// The blocks of lines in `text` that are separated by blank lines, each without its final
+/// newline. Extra blank lines between, before or after the blocks don't make empty ones.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+	let mut rest = text;
+	std::iter::from_fn(move || {
+		rest = rest.trim_start_matches('\n');
+		if rest.is_empty() {
+			return None;
+		}
+		let end = rest.find("\n\n").unwrap_or(rest.len());
+		let (section, after) = rest.split_at(end);
+		rest = after;
+		Some(section.trim_end_matches('\n'))
+	})
+}
+
+/// The lines of `section`, a slice of `input`, after its first line, which has to be `header`.
+pub fn section_body<'a>(
+	input: &str,
+	section: &'a str,
+	header: &str,
+) -> Result<&'a str, ParseError> {
+	let (first, body) = section.split_once('\n').unwrap_or((section, ""));
+	if first != header {
+		return Err(ParseError::at(input, first, format!("{header:?}")));
+	}
+	Ok(body)
+}
+
+/// Reads through `text`, a slice of `input`, from the front, for lines with a fixed layout
+/// that are easier to take a piece at a time than to split. Errors point at wherever the
+/// cursor had got to.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+	input: &'a str,
+	rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+	pub fn new(input: &'a str, text: &'a str) -> Cursor<'a> {
+		Cursor { input, rest: text }
+	}
+
+	/// The text that hasn't been read yet.
+	pub fn rest(&self) -> &'a str {
+		self.rest
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.rest.is_empty()
+	}
+
+	/// An error about the next character, or about the end if there isn't one.
+	pub fn error(&self, expected: impl Into<String>) -> ParseError {
+		let len = self.rest.chars().next().map_or(0, char::len_utf8);
+		ParseError::at(self.input, &self.rest[..len], expected)
+	}
+
+	/// Reads exactly `literal`.
+	pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+		self.rest = strip_prefix(self.input, self.rest, literal)?;
+		Ok(())
+	}
+
+	/// Reads the next `count` characters, whatever they are.
+	pub fn take(&mut self, count: usize, expected: &str) -> Result<&'a str, ParseError> {
+		let end = match self.rest.char_indices().nth(count) {
+			Some((end, _)) => end,
+			None if self.rest.chars().count() == count => self.rest.len(),
+			None => {
+				let end = &self.rest[self.rest.len()..];
+				return Err(ParseError::at(self.input, end, expected));
+			},
+		};
+		let (taken, rest) = self.rest.split_at(end);
+		self.rest = rest;
+		Ok(taken)
+	}
+
+	/// Reads characters for as long as `pred` accepts them, which might be none.
+	pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+		let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+		let (taken, rest) = self.rest.split_at(end);
+		self.rest = rest;
+		taken
+	}
+
+	/// Reads up to the next `separator`, and the separator itself, returning what came before.
+	pub fn until(&mut self, separator: &str) -> Result<&'a str, ParseError> {
+		let (taken, rest) = split_once(self.input, self.rest, separator)?;
+		self.rest = rest;
+		Ok(taken)
+	}
+
+	pub fn skip_whitespace(&mut self) {
+		self.take_while(char::is_whitespace);
+	}
+
+	/// Reads a number: digits, with an optional `-` or `+` in front.
+	pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+		let sign = self.rest.starts_with(['-', '+']) as usize;
+		let digits = self.rest[sign..]
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(self.rest.len() - sign);
+		if digits == 0 {
+			return Err(self.error("a number"));
+		}
+		let (text, rest) = self.rest.split_at(sign + digits);
+		let n = number(self.input, text)?;
+		self.rest = rest;
+		Ok(n)
+	}
+
+	/// Checks that everything has been read.
+	pub fn finish(self) -> Result<(), ParseError> {
+		if self.rest.is_empty() {
+			Ok(())
+		} else {
+			Err(ParseError::at(self.input, self.rest, "the end of the line"))
+		}
+	}
+}
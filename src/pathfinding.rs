@@ -0,0 +1,182 @@
+//! Shortest path searches over any state type. A search is described by where it starts, how to
+//! get from a state to its successors, and when it has arrived; the result has the cost, every
+//! state along the way and how much work it took to find.
+
+use std::{
+	cmp::Reverse,
+	collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+	hash::Hash,
+	ops::Add,
+};
+
+/// How much of the state space a search looked at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+	/// states whose successors were generated
+	pub expanded: usize,
+	/// distinct states that were reached at all
+	pub discovered: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+	pub cost: C,
+	/// every state from the start to the goal, both included
+	pub path: Vec<S>,
+	pub stats: Stats,
+}
+
+/// The states seen so far, each with the index of the state it was first reached from.
+struct Visited<S> {
+	index: HashMap<S, usize>,
+	states: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+	fn new() -> Visited<S> {
+		Visited {
+			index: HashMap::new(),
+			states: Vec::new(),
+		}
+	}
+
+	/// The index for `state`, and whether it was new.
+	fn insert(&mut self, state: S, parent: Option<usize>) -> (usize, bool) {
+		match self.index.entry(state) {
+			Entry::Occupied(e) => (*e.get(), false),
+			Entry::Vacant(e) => {
+				let i = self.states.len();
+				self.states.push((e.key().clone(), parent));
+				e.insert(i);
+				(i, true)
+			},
+		}
+	}
+
+	fn path_to(&self, mut i: usize) -> Vec<S> {
+		let mut path = vec![self.states[i].0.clone()];
+		while let Some(parent) = self.states[i].1 {
+			path.push(self.states[parent].0.clone());
+			i = parent;
+		}
+		path.reverse();
+		path
+	}
+}
+
+/// Breadth-first search, where every step costs 1. Returns `None` if no goal can be reached.
+pub fn bfs<S, I>(
+	starts: impl IntoIterator<Item = S>,
+	mut successors: impl FnMut(&S) -> I,
+	mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+	S: Clone + Eq + Hash,
+	I: IntoIterator<Item = S>,
+{
+	let mut visited = Visited::new();
+	let mut queue = VecDeque::new();
+	for start in starts {
+		if let (i, true) = visited.insert(start, None) {
+			queue.push_back((i, 0));
+		}
+	}
+
+	let mut stats = Stats::default();
+	while let Some((i, cost)) = queue.pop_front() {
+		let state = visited.states[i].0.clone();
+		if is_goal(&state) {
+			stats.discovered = visited.states.len();
+			return Some(Path {
+				cost,
+				path: visited.path_to(i),
+				stats,
+			});
+		}
+
+		stats.expanded += 1;
+		for next in successors(&state) {
+			if let (j, true) = visited.insert(next, Some(i)) {
+				queue.push_back((j, cost + 1));
+			}
+		}
+	}
+	None
+}
+
+/// Dijkstra's algorithm: the cheapest path when steps have different non-negative costs.
+pub fn dijkstra<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	successors: impl FnMut(&S) -> I,
+	is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Add<Output = C> + Default,
+	I: IntoIterator<Item = (S, C)>,
+{
+	astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the cost from a state to the nearest goal, and the path
+/// found is only guaranteed to be the cheapest if it never overestimates.
+pub fn astar<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	mut successors: impl FnMut(&S) -> I,
+	mut heuristic: impl FnMut(&S) -> C,
+	mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Add<Output = C> + Default,
+	I: IntoIterator<Item = (S, C)>,
+{
+	let mut visited = Visited::new();
+	// the cheapest known cost to each state, by index, and whether it's been expanded
+	let mut best: Vec<(C, bool)> = Vec::new();
+	let mut fringe = BinaryHeap::new();
+
+	for start in starts {
+		if let (i, true) = visited.insert(start, None) {
+			best.push((C::default(), false));
+			let estimate = heuristic(&visited.states[i].0);
+			fringe.push(Reverse((estimate, C::default(), i)));
+		}
+	}
+
+	let mut stats = Stats::default();
+	while let Some(Reverse((_, cost, i))) = fringe.pop() {
+		if best[i].1 || cost > best[i].0 {
+			// already expanded through a cheaper path
+			continue;
+		}
+		best[i].1 = true;
+
+		let state = visited.states[i].0.clone();
+		if is_goal(&state) {
+			stats.discovered = visited.states.len();
+			return Some(Path {
+				cost,
+				path: visited.path_to(i),
+				stats,
+			});
+		}
+
+		stats.expanded += 1;
+		for (next, step_cost) in successors(&state) {
+			let next_cost = cost + step_cost;
+			let (j, new) = visited.insert(next, Some(i));
+			if new {
+				best.push((next_cost, false));
+			} else if best[j].1 || next_cost >= best[j].0 {
+				continue;
+			} else {
+				best[j].0 = next_cost;
+				visited.states[j].1 = Some(i);
+			}
+			let estimate = next_cost + heuristic(&visited.states[j].0);
+			fringe.push(Reverse((estimate, next_cost, j)));
+		}
+	}
+	None
+}
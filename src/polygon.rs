@@ -0,0 +1,96 @@
+//! Polygons whose corners are on the integer lattice, like a loop traced through a grid. The
+//! shoelace formula gives their area, and Pick's theorem turns that into a count of the lattice
+//! points inside, which is how many tiles a loop encloses without having to visit them.
+
+use crate::{
+	checked::Checked,
+	geometry::{Direction, Point},
+	math,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+	vertices: Vec<Point<i64>>,
+}
+
+impl Polygon {
+	/// The polygon through `vertices` in order, closed back to the first.
+	pub fn from_vertices(vertices: impl IntoIterator<Item = Point<i64>>) -> Polygon {
+		Polygon {
+			vertices: vertices.into_iter().collect(),
+		}
+	}
+
+	/// The polygon traced by starting at `start` and making each move in turn. The moves should
+	/// end back at `start`.
+	pub fn from_moves(
+		start: Point<i64>,
+		moves: impl IntoIterator<Item = (Direction, i64)>,
+	) -> Polygon {
+		let mut vertices = vec![start];
+		let mut position = start;
+		for (direction, distance) in moves {
+			position = position + direction.offset() * distance;
+			vertices.push(position);
+		}
+		if vertices.len() > 1 && vertices.last() == Some(&start) {
+			vertices.pop();
+		}
+		Polygon { vertices }
+	}
+
+	pub fn vertices(&self) -> &[Point<i64>] {
+		&self.vertices
+	}
+
+	fn edges(&self) -> impl Iterator<Item = (Point<i64>, Point<i64>)> + '_ {
+		let next = self.vertices.iter().cycle().skip(1);
+		self.vertices.iter().copied().zip(next.copied())
+	}
+
+	/// Twice the area, positive if the vertices go round counterclockwise (with `y` pointing
+	/// up) and negative if they go clockwise. Doubled so that it's always a whole number.
+	pub fn double_signed_area(&self) -> i64 {
+		// shoelace formula
+		self.edges()
+			.fold(Checked(0), |area, (a, b)| {
+				area + (Checked(a.x) * b.y - Checked(b.x) * a.y)
+			})
+			.get()
+	}
+
+	/// Twice the area, unsigned.
+	fn double_area(&self) -> Checked<i64> {
+		// `abs` would wrap for `i64::MIN`
+		Checked(0) + self.double_signed_area().max(0) - self.double_signed_area().min(0)
+	}
+
+	/// The area, rounded down to a whole number if it's a half.
+	pub fn area(&self) -> i64 {
+		(self.double_area() / 2).get()
+	}
+
+	/// How many lattice points are on the edges, which for edges along the axes is the same as
+	/// the perimeter.
+	pub fn boundary_points(&self) -> i64 {
+		let points = self.edges().fold(Checked(0u64), |points, (a, b)| {
+			let dx = (Checked(b.x) - a.x).get();
+			let dy = (Checked(b.y) - a.y).get();
+			points + math::gcd(dx.unsigned_abs(), dy.unsigned_abs())
+		});
+		i64::try_from(points.get())
+			.unwrap_or_else(|_| panic!("overflow: {points} boundary points don't fit in i64"))
+	}
+
+	/// How many lattice points are strictly inside, by Pick's theorem:
+	/// `area = interior + boundary / 2 - 1`.
+	pub fn interior_points(&self) -> i64 {
+		((self.double_area() - self.boundary_points()) / 2 + 1).get()
+	}
+
+	/// How many tiles a loop through the centres of tiles covers, counting the ones the loop
+	/// goes through as well as the ones it encloses.
+	pub fn tiles_covered(&self) -> i64 {
+		(Checked(self.interior_points()) + self.boundary_points()).get()
+	}
+}
@@ -0,0 +1,123 @@
+//! Reading what we need out of a puzzle page's HTML. The pages are simple and regular enough
+//! that searching for tags works fine without a real HTML parser.
+
+use crate::Part;
+
+const ARTICLE: &str = "<article class=\"day-desc\">";
+const BLOCK_START: &str = "<pre><code>";
+const BLOCK_END: &str = "</code></pre>";
+const ANSWER_START: &str = "<code><em>";
+const ANSWER_END: &str = "</em></code>";
+
+/// Undoes the escaping the site applies inside `<code>` blocks.
+pub fn unescape(s: &str) -> String {
+	s.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&amp;", "&")
+}
+
+/// Removes tags like the `<em>` used to highlight parts of a code block, keeping their text.
+pub fn strip_tags(s: &str) -> String {
+	let mut out = String::with_capacity(s.len());
+	let mut in_tag = false;
+	for c in s.chars() {
+		match c {
+			'<' => in_tag = true,
+			'>' if in_tag => in_tag = false,
+			c if !in_tag => out.push(c),
+			_ => {},
+		}
+	}
+	out
+}
+
+/// One `<pre><code>` block from a puzzle page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+	/// which part's description the block appears in
+	pub part: Part,
+	pub input: String,
+	/// The highlighted `<code><em>` value that most likely answers this example. It's a guess:
+	/// not every block is an input, and the text doesn't always end on the answer.
+	pub answer: Option<String>,
+}
+
+/// Every highlighted value in `html`, in order.
+fn highlighted(html: &str) -> Vec<String> {
+	let mut values = Vec::new();
+	let mut rest = html;
+	while let Some(start) = rest.find(ANSWER_START) {
+		rest = &rest[start + ANSWER_START.len()..];
+		let Some(len) = rest.find(ANSWER_END) else {
+			break;
+		};
+		values.push(unescape(&strip_tags(&rest[..len])));
+		rest = &rest[len..];
+	}
+	values
+}
+
+/// Where the text introducing the next block starts, if `between` (everything from one block to
+/// the next) ends on a paragraph like "here is a situation that takes <em>6</em> steps:".
+/// Anything highlighted there is about the next block, not the previous one.
+fn intro_start(between: &str) -> Option<usize> {
+	let start = between.rfind("<p>")?;
+	let paragraph = between[start..].trim_end().strip_suffix("</p>")?;
+	strip_tags(paragraph)
+		.trim_end()
+		.ends_with(':')
+		.then_some(start)
+}
+
+/// Every `<pre><code>` block in `article`, as byte ranges including the tags.
+fn blocks(article: &str) -> Vec<(usize, usize)> {
+	let mut blocks = Vec::new();
+	let mut offset = 0;
+	while let Some(start) = article[offset..].find(BLOCK_START) {
+		let start = offset + start;
+		let Some(len) = article[start..].find(BLOCK_END) else {
+			break;
+		};
+		offset = start + len + BLOCK_END.len();
+		blocks.push((start, offset));
+	}
+	blocks
+}
+
+/// Every example block on the page, with a guess at each one's answer. Part 2's description is
+/// only on the page once part 1 has been solved.
+pub fn examples(html: &str) -> Vec<Example> {
+	let mut examples = Vec::new();
+
+	for (i, article) in html.split(ARTICLE).skip(1).enumerate() {
+		let part = if i == 0 { Part::One } else { Part::Two };
+		let article = &article[..article.find("</article>").unwrap_or(article.len())];
+		let blocks = blocks(article);
+
+		// the text about each block runs from its end up to the next one
+		let mut intro: Vec<String> = Vec::new();
+		for (j, &(start, end)) in blocks.iter().enumerate() {
+			let next = blocks.get(j + 1).map_or(article.len(), |b| b.0);
+			let between = &article[end..next];
+			let (about, next_intro) = match intro_start(between) {
+				Some(split) if j + 1 < blocks.len() => between.split_at(split),
+				_ => (between, ""),
+			};
+
+			let mut candidates = intro;
+			candidates.extend(highlighted(about));
+			intro = highlighted(next_intro);
+
+			let input = &article[start + BLOCK_START.len()..end - BLOCK_END.len()];
+			examples.push(Example {
+				part,
+				input: unescape(&strip_tags(input)),
+				answer: candidates.pop(),
+			});
+		}
+	}
+
+	examples
+}
@@ -0,0 +1,121 @@
+//! Starting a new day for `aoc new`: a module with a `Solution` skeleton, registered in
+//! `days::ALL`, with an example answer in the registry so `cargo test` checks it from the start.
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	answers::{self, Expected},
+	Answer, Part,
+};
+
+fn template(day: u8) -> String {
+	format!(
+		"\
+use crate::{{parse::ParseError, Answer, Solution}};
+
+pub struct Day{day:02} {{
+	lines: Vec<String>,
+}}
+
+impl Solution for Day{day:02} {{
+	// part 2 is only revealed once part 1 is solved; remove this then and implement `part2`
+	const HAS_PART2: bool = false;
+
+	fn parse(input: &str) -> Result<Self, ParseError> {{
+		Ok(Day{day:02} {{
+			lines: input.lines().map(str::to_string).collect(),
+		}})
+	}}
+
+	fn part1(&self) -> Answer {{
+		todo!(\"day {day} part 1 ({{}} lines of input)\", self.lines.len())
+	}}
+}}
+"
+	)
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping the run sorted.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) -> Result<(), String> {
+	let run: Vec<usize> = (0..lines.len())
+		.filter(|&i| lines[i].starts_with(prefix))
+		.collect();
+	let Some(&last) = run.last() else {
+		return Err(format!(
+			"no lines starting with {prefix:?} to add {line:?} to"
+		));
+	};
+	let at = run
+		.iter()
+		.copied()
+		.find(|&i| lines[i] > line)
+		.unwrap_or(last + 1);
+	lines.insert(at, line);
+	Ok(())
+}
+
+/// `days/mod.rs` with the new day's module declared and added to `ALL`.
+pub fn register(source: &str, day: u8) -> Result<String, String> {
+	let module = format!("day{day:02}");
+	let declaration = format!("pub mod {module};");
+	if source.lines().any(|l| l.trim() == declaration) {
+		return Err(format!("{module} is already registered"));
+	}
+
+	let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+	insert_sorted(&mut lines, "pub mod day", declaration)?;
+	insert_sorted(
+		&mut lines,
+		"\tDay::new::<day",
+		format!("\tDay::new::<{module}::Day{day:02}>({day}),"),
+	)?;
+	Ok(lines.join("\n") + "\n")
+}
+
+/// Creates day `day` under `root`, refusing to touch anything if the day already exists, and
+/// returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+	if !(1..=25).contains(&day) {
+		return Err(format!("invalid day {day}"));
+	}
+	let module_path = root.join("src/days").join(format!("day{day:02}.rs"));
+	let mod_path = root.join("src/days/mod.rs");
+	let registry_path = root.join(answers::REGISTRY_PATH);
+
+	if module_path.exists() {
+		return Err(format!("{} already exists", module_path.display()));
+	}
+	let mod_source = fs::read_to_string(&mod_path)
+		.map_err(|e| format!("couldn't read {}: {e}", mod_path.display()))?;
+	let mod_source = register(&mod_source, day)?;
+
+	fs::write(&module_path, template(day))
+		.map_err(|e| format!("couldn't write {}: {e}", module_path.display()))?;
+	fs::write(&mod_path, mod_source)
+		.map_err(|e| format!("couldn't write {}: {e}", mod_path.display()))?;
+	let mut changed = vec![module_path, mod_path];
+
+	// a placeholder keeps `cargo test` failing until the real answer is filled in
+	let placeholder = Expected {
+		input: format!("example{day}"),
+		day,
+		part: Part::One,
+		answer: Answer::Text("TODO".to_string()),
+	};
+	let known = answers::load(&registry_path)?
+		.iter()
+		.any(|e| e.is_example() && e.day == day && e.part == Part::One);
+	if !known {
+		answers::add(
+			&registry_path,
+			&[placeholder],
+			"added by `aoc new`; replace with the example's answer from the puzzle",
+		)?;
+		changed.push(registry_path);
+	}
+
+	Ok(changed)
+}
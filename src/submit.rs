@@ -0,0 +1,310 @@
+//! Sending answers for `aoc submit`. Every attempt is kept in a history file, and answers the
+//! site has already ruled out are refused locally instead of costing another wrong guess (and the
+//! wait that comes with it).
+
+use std::{
+	fmt::Display,
+	fs::{self, OpenOptions},
+	io::Write,
+	path::Path,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{fetch::Client, puzzle, Answer, Part};
+
+/// Where `aoc submit` keeps its history, relative to the crate root.
+pub const HISTORY_PATH: &str = ".aoc/submissions.tsv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+	Correct,
+	TooHigh,
+	TooLow,
+	/// wrong, without a hint about which way
+	Wrong,
+	/// sent too soon after the last answer, so it wasn't checked
+	TooSoon,
+	/// the part has already been solved
+	AlreadySolved,
+	/// a response we don't recognize
+	Unknown,
+}
+
+impl Verdict {
+	fn name(&self) -> &'static str {
+		match self {
+			Verdict::Correct => "correct",
+			Verdict::TooHigh => "too-high",
+			Verdict::TooLow => "too-low",
+			Verdict::Wrong => "wrong",
+			Verdict::TooSoon => "too-soon",
+			Verdict::AlreadySolved => "already-solved",
+			Verdict::Unknown => "unknown",
+		}
+	}
+
+	fn from_name(name: &str) -> Option<Verdict> {
+		[
+			Verdict::Correct,
+			Verdict::TooHigh,
+			Verdict::TooLow,
+			Verdict::Wrong,
+			Verdict::TooSoon,
+			Verdict::AlreadySolved,
+			Verdict::Unknown,
+		]
+		.into_iter()
+		.find(|v| v.name() == name)
+	}
+
+	/// Whether the site actually checked the answer.
+	fn judged(&self) -> bool {
+		matches!(
+			self,
+			Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+		)
+	}
+}
+
+impl Display for Verdict {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.name())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+	pub verdict: Verdict,
+	/// how long the site wants us to wait before sending another answer
+	pub wait: Option<Duration>,
+	/// the text of the response, for showing to the user
+	pub message: String,
+}
+
+/// Reads a wait like "one minute", "5 minutes" or "1m 23s" from the start of `s`.
+fn parse_wait(s: &str) -> Option<Duration> {
+	let mut words = s.split_whitespace();
+	let first = words.next()?;
+	let minutes = match first {
+		"one" => Some(1),
+		n => n.parse::<u64>().ok(),
+	};
+	if let Some(minutes) = minutes {
+		return words
+			.next()?
+			.starts_with("minute")
+			.then(|| Duration::from_secs(minutes * 60));
+	}
+
+	let mut secs = 0;
+	for word in s.split_whitespace() {
+		if let Some(m) = word.strip_suffix('m').and_then(|m| m.parse::<u64>().ok()) {
+			secs += m * 60;
+		} else if let Some(s) = word.strip_suffix('s').and_then(|s| s.parse::<u64>().ok()) {
+			secs += s;
+		} else {
+			break;
+		}
+	}
+	(secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// Works out what the page returned after submitting an answer says.
+pub fn parse_response(html: &str) -> Response {
+	let message = match (html.find("<article>"), html.find("</article>")) {
+		(Some(start), Some(end)) if start < end => &html[start..end],
+		_ => html,
+	};
+	let message = puzzle::unescape(&puzzle::strip_tags(message))
+		.split_whitespace()
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	let verdict = if message.contains("That's the right answer") {
+		Verdict::Correct
+	} else if message.contains("your answer is too high") {
+		Verdict::TooHigh
+	} else if message.contains("your answer is too low") {
+		Verdict::TooLow
+	} else if message.contains("That's not the right answer") {
+		Verdict::Wrong
+	} else if message.contains("You gave an answer too recently") {
+		Verdict::TooSoon
+	} else if message.contains("Did you already complete it") {
+		Verdict::AlreadySolved
+	} else {
+		Verdict::Unknown
+	};
+
+	let wait = if let Some(i) = message.find("You have ") {
+		parse_wait(&message[i + "You have ".len()..])
+	} else if let Some(i) = message.find("wait ") {
+		parse_wait(&message[i + "wait ".len()..])
+	} else {
+		None
+	};
+
+	Response {
+		verdict,
+		wait,
+		message,
+	}
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+	/// seconds since the Unix epoch
+	pub timestamp: u64,
+	pub day: u8,
+	pub part: Part,
+	pub answer: String,
+	pub verdict: Verdict,
+	/// when the site will take another answer, in seconds since the Unix epoch, or 0
+	pub retry_at: u64,
+}
+
+impl Attempt {
+	fn to_line(&self) -> String {
+		format!(
+			"{}\t{}\t{}\t{}\t{}\t{}",
+			self.timestamp, self.day, self.part, self.answer, self.verdict, self.retry_at
+		)
+	}
+
+	fn from_line(line: &str) -> Option<Attempt> {
+		let fields: Vec<&str> = line.split('\t').collect();
+		if fields.len() != 6 {
+			return None;
+		}
+		Some(Attempt {
+			timestamp: fields[0].parse().ok()?,
+			day: fields[1].parse().ok()?,
+			part: fields[2].parse().ok()?,
+			answer: fields[3].to_string(),
+			verdict: Verdict::from_name(fields[4])?,
+			retry_at: fields[5].parse().ok()?,
+		})
+	}
+}
+
+/// Reads every attempt in the history file. A missing file is an empty history, and lines that
+/// don't parse are skipped.
+pub fn load_history(path: &Path) -> Vec<Attempt> {
+	fs::read_to_string(path)
+		.map(|s| s.lines().filter_map(Attempt::from_line).collect())
+		.unwrap_or_default()
+}
+
+pub fn append_history(path: &Path, attempt: &Attempt) -> std::io::Result<()> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+	let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+	writeln!(file, "{}", attempt.to_line())
+}
+
+pub fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0)
+}
+
+/// Refuses `answer` if the history already shows what the site would say: the part is solved,
+/// we're still waiting out the last attempt, or the answer was tried or lies beyond an answer
+/// that was too high or too low.
+pub fn check(
+	history: &[Attempt],
+	day: u8,
+	part: Part,
+	answer: &Answer,
+	now: u64,
+) -> Result<(), String> {
+	let answer_text = answer.to_string();
+	if answer_text.is_empty() || answer_text.contains(['\t', '\n']) {
+		return Err(format!("{answer_text:?} can't be submitted"));
+	}
+
+	let attempts: Vec<&Attempt> = history
+		.iter()
+		.filter(|a| a.day == day && a.part == part)
+		.collect();
+
+	for attempt in &attempts {
+		match attempt.verdict {
+			Verdict::Correct => {
+				return Err(format!(
+					"day {day} part {part} was already solved with {}",
+					attempt.answer
+				))
+			},
+			Verdict::AlreadySolved => {
+				return Err(format!("day {day} part {part} was already solved"))
+			},
+			_ => {},
+		}
+	}
+
+	if let Some(retry_at) = history
+		.iter()
+		.filter(|a| a.day == day)
+		.map(|a| a.retry_at)
+		.max()
+	{
+		if retry_at > now {
+			return Err(format!(
+				"wait {}s before sending another answer",
+				retry_at - now
+			));
+		}
+	}
+
+	for attempt in attempts.iter().filter(|a| a.verdict.judged()) {
+		if attempt.answer == answer_text {
+			return Err(format!(
+				"{answer_text} was already submitted and was {}",
+				attempt.verdict
+			));
+		}
+		let (Answer::Number(n), Ok(tried)) = (answer, attempt.answer.parse::<i128>()) else {
+			continue;
+		};
+		match attempt.verdict {
+			Verdict::TooHigh if *n >= tried => {
+				return Err(format!("{n} is too high: {tried} already was"))
+			},
+			Verdict::TooLow if *n <= tried => {
+				return Err(format!("{n} is too low: {tried} already was"))
+			},
+			_ => {},
+		}
+	}
+
+	Ok(())
+}
+
+/// Submits `answer` unless the history at `history_path` rules it out, and records the attempt.
+pub fn submit(
+	client: &Client,
+	history_path: &Path,
+	day: u8,
+	part: Part,
+	answer: &Answer,
+) -> Result<Response, String> {
+	let timestamp = now();
+	check(&load_history(history_path), day, part, answer, timestamp)?;
+
+	let response = parse_response(&client.submit(day, part, &answer.to_string())?);
+	let attempt = Attempt {
+		timestamp,
+		day,
+		part,
+		answer: answer.to_string(),
+		verdict: response.verdict,
+		retry_at: response.wait.map_or(0, |w| timestamp + w.as_secs()),
+	};
+	append_history(history_path, &attempt)
+		.map_err(|e| format!("couldn't write {}: {e}", history_path.display()))?;
+	Ok(response)
+}
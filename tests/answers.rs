@@ -0,0 +1,163 @@
+mod common;
+
+use std::{fs, panic, path::Path};
+
+use aoc2023::{
+	answers::{self, Expected},
+	input, Answer, Day, Part,
+};
+
+fn root() -> &'static Path {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Solves every registered answer that `filter` picks and panics with a list of the ones that
+/// came out wrong. Inputs that aren't on disk are skipped unless `require_input` is set.
+fn check(filter: impl Fn(&Expected) -> bool, require_input: bool) {
+	let registry = answers::load(&root().join("answers.toml")).unwrap();
+	let mut failures: Vec<String> = Vec::new();
+	let mut checked = 0;
+
+	for expected in registry.iter().filter(|e| filter(e)) {
+		let path = expected.input_path(root());
+		let input = match fs::read_to_string(&path) {
+			Ok(raw) => input::normalize(&raw).text,
+			Err(_) if !require_input => continue,
+			Err(e) => {
+				failures.push(format!("{}: {e}", path.display()));
+				continue;
+			},
+		};
+
+		let name = format!("{} part {}", expected.input, expected.part);
+		let Some(day) = Day::find(expected.day) else {
+			failures.push(format!("{name}: day {} is not implemented", expected.day));
+			continue;
+		};
+		// a day that's still being written panics with `todo!`; report it with the rest
+		let solved = panic::catch_unwind(|| day.solve(&input, &[expected.part]));
+		let report = match solved {
+			Ok(Ok(mut reports)) => reports.remove(0),
+			Ok(Err(e)) => {
+				failures.push(format!("{name}: {e}"));
+				checked += 1;
+				continue;
+			},
+			Err(_) => {
+				failures.push(format!("{name}: panicked"));
+				checked += 1;
+				continue;
+			},
+		};
+		let answer = &report.answer;
+		if *answer != expected.answer {
+			failures.push(format!(
+				"{name}: expected {}, got {answer}",
+				expected.answer
+			));
+		}
+		checked += 1;
+	}
+
+	assert!(
+		failures.is_empty(),
+		"{} of {} answers were wrong:\n{}",
+		failures.len(),
+		checked,
+		failures.join("\n")
+	);
+}
+
+#[test]
+fn examples() {
+	check(Expected::is_example, true);
+}
+
+#[test]
+fn inputs() {
+	check(|e| !e.is_example(), false);
+}
+
+#[test]
+fn every_day_has_an_example() {
+	let registry = answers::load(&root().join("answers.toml")).unwrap();
+	for day in aoc2023::days::ALL {
+		for &part in day.parts() {
+			assert!(
+				registry
+					.iter()
+					.any(|e| e.is_example() && e.day == day.number && e.part == part),
+				"day {} part {part} has no example answer in answers.toml",
+				day.number
+			);
+		}
+	}
+}
+
+fn expected(input: &str, part: Part, answer: Answer) -> Expected {
+	Expected {
+		input: input.to_string(),
+		day: 6,
+		part,
+		answer,
+	}
+}
+
+#[test]
+fn add_goes_into_existing_sections() {
+	let dir = common::temp_dir("registry");
+	let registry = dir.join("answers.toml");
+	fs::write(
+		&registry,
+		"[example6]\npart1 = 288\n\n# real input\n[input6]\npart1 = 1\n",
+	)
+	.unwrap();
+
+	let added = answers::add(
+		&registry,
+		&[
+			expected("example6", Part::Two, Answer::Number(71503)),
+			expected("example6-2", Part::One, Answer::Number(4)),
+		],
+		"proposed",
+	)
+	.unwrap();
+	assert_eq!(added.len(), 2);
+	assert_eq!(
+		fs::read_to_string(&registry).unwrap(),
+		"[example6]\npart1 = 288\n# proposed\npart2 = 71503\n\n# real input\n[input6]\npart1 = 1\n\n\
+		 # proposed\n[example6-2]\npart1 = 4\n"
+	);
+	assert_eq!(answers::load(&registry).unwrap().len(), 4);
+
+	let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn text_answers_round_trip() {
+	let dir = common::temp_dir("registry-text");
+	let registry = dir.join("answers.toml");
+	fs::write(&registry, "").unwrap();
+
+	let text = Answer::Text("say \"hi\" \\ bye\n\u{1}".to_string());
+	answers::add(
+		&registry,
+		&[expected("example6", Part::One, text.clone())],
+		"text",
+	)
+	.unwrap();
+	assert!(fs::read_to_string(&registry)
+		.unwrap()
+		.contains(r#"part1 = "say \"hi\" \\ bye\n\u0001""#));
+	assert_eq!(answers::load(&registry).unwrap()[0].answer, text);
+
+	// a bare quote or an escape TOML doesn't have is an error, not part of the answer
+	assert!(answers::parse("[example6]\npart1 = \"a\"b\"\n").is_err());
+	assert!(answers::parse("[example6]\npart1 = \"\\q\"\n").is_err());
+	assert_eq!(
+		answers::parse("[example6]\npart1 = \"\\u00e9\"\n").unwrap()[0].answer,
+		Answer::Text("é".to_string())
+	);
+
+	let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,60 @@
+use std::{env, fs, time::Duration};
+
+use aoc2023::{
+	bench::{self, Phase, Stats, Subject},
+	params::Params,
+	Day,
+};
+
+fn ms(n: u64) -> Duration {
+	Duration::from_millis(n)
+}
+
+#[test]
+fn stats() {
+	let stats = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]);
+	assert_eq!(stats.iterations, 4);
+	assert_eq!(stats.min, ms(1));
+	assert_eq!(stats.median, Duration::from_micros(2500));
+	// population standard deviation of 1, 2, 3, 4 is sqrt(1.25)
+	assert!((stats.stddev.as_secs_f64() - 1.25f64.sqrt() / 1000.0).abs() < 1e-9);
+}
+
+#[test]
+fn history_round_trip() {
+	let path = env::temp_dir().join(format!("aoc-bench-history-{}.tsv", std::process::id()));
+	let _ = fs::remove_file(&path);
+	assert!(bench::load_history(&path).is_empty());
+
+	let day = Day::find(11).unwrap();
+	let defaults = Params::defaults(day.params);
+	let subject = Subject::new(11, "#.\n..\n", &defaults);
+	let first = Stats::from_samples(&[ms(10)]);
+	let second = Stats::from_samples(&[ms(12)]);
+	bench::append_history(
+		&path,
+		&subject,
+		&[(Phase::Parse, first), (Phase::Part1, first)],
+	)
+	.unwrap();
+	bench::append_history(&path, &subject, &[(Phase::Part1, second)]).unwrap();
+
+	let history = bench::load_history(&path);
+	fs::remove_file(&path).unwrap();
+	assert_eq!(history.len(), 3);
+
+	let previous = bench::previous(&history, &subject, Phase::Part1).unwrap();
+	assert_eq!(previous.stats, second);
+	assert!(bench::previous(&history, &subject, Phase::Part2).is_none());
+	assert!((bench::slowdown(&first, &second) - 0.2).abs() < 1e-9);
+
+	// timings of another input, or other parameters, aren't comparable
+	let other_input = Subject::new(11, "..\n.#\n", &defaults);
+	assert!(bench::previous(&history, &other_input, Phase::Part1).is_none());
+	let params = day
+		.resolve_params(&[("expansion".to_string(), 10)])
+		.unwrap();
+	let other_params = Subject::new(11, "#.\n..\n", &params);
+	assert_eq!(other_params.params, "expansion=10");
+	assert!(bench::previous(&history, &other_params, Phase::Part1).is_none());
+}
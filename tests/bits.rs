@@ -0,0 +1,48 @@
+use aoc2023::{
+	bits::{BitGrid, BitSet},
+	grid::Grid,
+};
+
+#[test]
+fn sets() {
+	let mut a: BitSet = [3, 64, 130].into_iter().collect();
+	let b: BitSet = [3, 65].into_iter().collect();
+	assert!(a.contains(64) && !a.contains(65) && !a.contains(1000));
+	assert_eq!(a.len(), 3);
+	assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [3]);
+	assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), [3, 64, 65, 130]);
+	assert_eq!(a.xor(&b).iter().collect::<Vec<_>>(), [64, 65, 130]);
+	assert_eq!(a.difference(&b).len(), 2);
+
+	assert!(!a.insert(3));
+	assert!(a.remove(130));
+	assert!(!a.remove(130));
+	// removing the highest value drops its word, so the sets still compare equal
+	assert_eq!(a, [3, 64].into_iter().collect());
+	assert!(a.xor(&a).is_empty());
+}
+
+#[test]
+fn grids() {
+	let input = "#..\n..#\n";
+	let grid = Grid::parse_with(input, input.lines(), ". or #", |c| match c {
+		'#' => Some(true),
+		'.' => Some(false),
+		_ => None,
+	})
+	.unwrap();
+	let mut bits = BitGrid::from(&grid);
+	assert_eq!((bits.width(), bits.height()), (3, 2));
+	assert_eq!(bits.count_ones(), 2);
+	assert_eq!(bits.ones().collect::<Vec<_>>(), [(0, 0), (2, 1)]);
+	assert!(bits.column(1).is_empty());
+	assert_eq!(bits.column(2).iter().collect::<Vec<_>>(), [1]);
+
+	let transposed = bits.transpose();
+	assert_eq!((transposed.width(), transposed.height()), (2, 3));
+	assert!(transposed.get(1, 2));
+
+	bits.set(1, 0, true);
+	bits.set(0, 0, false);
+	assert_eq!(bits.row(0).xor(bits.row(1)).len(), 2);
+}
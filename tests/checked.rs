@@ -0,0 +1,150 @@
+use std::panic;
+
+use aoc2023::{
+	checked::Checked,
+	interval::{Interval, IntervalBox},
+	Answer, Day, Part,
+};
+
+/// The message `f` panicked with.
+fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+	let payload = panic::catch_unwind(f).unwrap_err();
+	match payload.downcast::<String>() {
+		Ok(message) => *message,
+		Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+	}
+}
+
+#[test]
+fn arithmetic() {
+	let mut x = Checked(7u64);
+	x *= 6;
+	x -= Checked(2);
+	assert_eq!(x.get(), 40);
+	assert_eq!((x / 3 % 5).get(), 3);
+
+	let sum: Checked<i32> = [1, -2, 3].into_iter().map(Checked).sum();
+	assert_eq!(Answer::from(sum), Answer::Number(2));
+}
+
+#[test]
+fn overflow_reports_the_operation() {
+	assert_eq!(
+		panic_message(|| {
+			let _ = Checked(200u8) * 2;
+		}),
+		"overflow: 200 * 2 doesn't fit in u8"
+	);
+	assert_eq!(
+		panic_message(|| {
+			let mut x = Checked(0usize);
+			x -= 1;
+		}),
+		"overflow: 0 - 1 doesn't fit in usize"
+	);
+	assert_eq!(
+		panic_message(|| {
+			let _: Checked<i64> = [i64::MAX, 1].into_iter().map(Checked).sum();
+		}),
+		format!("overflow: {} + 1 doesn't fit in i64", i64::MAX)
+	);
+}
+
+#[test]
+fn library_arithmetic_is_checked() {
+	let huge = Interval::new(0, 1 << 40);
+	assert_eq!(
+		panic_message(|| {
+			IntervalBox::new([huge; 2]).volume();
+		}),
+		format!(
+			"overflow: {} * {} doesn't fit in i64",
+			1i64 << 40,
+			1i64 << 40
+		)
+	);
+	assert_eq!(
+		panic_message(|| {
+			Interval::new(i64::MIN, 1).len();
+		}),
+		format!("overflow: 1 - {} doesn't fit in i64", i64::MIN)
+	);
+	assert_eq!(
+		panic_message(|| {
+			Interval::from_len(i64::MAX, 1);
+		}),
+		format!("overflow: {} + 1 doesn't fit in i64", i64::MAX)
+	);
+	assert_eq!(
+		panic_message(|| {
+			Interval::inclusive(0, i64::MAX);
+		}),
+		format!("overflow: {} + 1 doesn't fit in i64", i64::MAX)
+	);
+	assert_eq!(
+		panic_message(|| {
+			Interval::new(0, 10).shift(i64::MAX);
+		}),
+		format!("overflow: 10 + {} doesn't fit in i64", i64::MAX)
+	);
+}
+
+#[test]
+fn days_check_their_arithmetic() {
+	let solve = |day: u8, input: String, part: Part| {
+		panic_message(move || {
+			let _ = Day::find(day).unwrap().solve(&input, &[part]);
+		})
+	};
+
+	// 33 matches are worth 2^32 points
+	let numbers: Vec<String> = (1..=33).map(|n| n.to_string()).collect();
+	let card = format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "));
+	assert_eq!(
+		solve(4, card, Part::One),
+		format!("overflow: {} * 2 doesn't fit in u32", 1u32 << 31)
+	);
+
+	assert_eq!(
+		solve(
+			2,
+			"Game 1: 70000 red, 70000 green, 1 blue\n".to_string(),
+			Part::Two
+		),
+		"overflow: 70000 * 70000 doesn't fit in u32"
+	);
+
+	let workflow = |value: &str| format!("in{{x>{value}:A,R}}\n\n{{x=1,m=2,a=3,s=4}}\n");
+	assert_eq!(
+		solve(19, workflow(&i64::MAX.to_string()), Part::Two),
+		format!("overflow: {} + 1 doesn't fit in i64", i64::MAX)
+	);
+	let error = Day::find(19)
+		.unwrap()
+		.solve(&workflow(&u64::MAX.to_string()), &[Part::One])
+		.unwrap_err();
+	assert_eq!(error.expected, "a rating that fits in i64");
+
+	let almanac = |seeds: &str, mapping: &str| {
+		let maps = [
+			"seed-to-soil",
+			"soil-to-fertilizer",
+			"fertilizer-to-water",
+			"water-to-light",
+			"light-to-temperature",
+			"temperature-to-humidity",
+			"humidity-to-location",
+		]
+		.map(|name| format!("{name} map:\n{mapping}\n"));
+		format!("seeds: {seeds}\n\n{}", maps.join("\n"))
+	};
+	let error = Day::find(5)
+		.unwrap()
+		.solve(&almanac(&u64::MAX.to_string(), "0 0 1"), &[Part::One])
+		.unwrap_err();
+	assert_eq!(error.expected, "numbers that fit in i64");
+	assert_eq!(
+		solve(5, almanac("0 1", &format!("{} 0 1", i64::MAX)), Part::Two),
+		format!("overflow: 1 + {} doesn't fit in i64", i64::MAX)
+	);
+}
@@ -0,0 +1,117 @@
+//! A tiny stand-in for the Advent of Code site, so the HTTP code can be tested without a network.
+
+#![allow(dead_code)]
+
+use std::{
+	env, fs,
+	io::{BufRead, BufReader, Write},
+	net::TcpListener,
+	path::PathBuf,
+	sync::{Arc, Mutex},
+	thread,
+};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+	pub method: String,
+	pub path: String,
+	pub headers: Vec<(String, String)>,
+	pub body: String,
+}
+
+impl Request {
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, v)| v.as_str())
+	}
+}
+
+pub struct MockServer {
+	pub url: String,
+	requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+	/// Serves requests on a free local port until the test ends. `respond` gets each request and
+	/// returns the status code and body to send back.
+	pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> MockServer {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+		let requests = Arc::new(Mutex::new(Vec::new()));
+
+		let log = requests.clone();
+		thread::spawn(move || {
+			for stream in listener.incoming() {
+				let Ok(mut stream) = stream else { continue };
+				let Some(request) = read_request(&mut BufReader::new(&stream)) else {
+					continue;
+				};
+				let (status, body) = respond(&request);
+				log.lock().unwrap().push(request);
+				let _ = write!(
+					stream,
+					"HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+					body.len()
+				);
+			}
+		});
+
+		MockServer { url, requests }
+	}
+
+	pub fn requests(&self) -> Vec<Request> {
+		self.requests.lock().unwrap().clone()
+	}
+}
+
+fn read_request(reader: &mut impl BufRead) -> Option<Request> {
+	let mut line = String::new();
+	reader.read_line(&mut line).ok()?;
+	let mut words = line.split_whitespace();
+	let method = words.next()?.to_string();
+	let path = words.next()?.to_string();
+
+	let mut headers = Vec::new();
+	loop {
+		line.clear();
+		reader.read_line(&mut line).ok()?;
+		let line = line.trim_end();
+		if line.is_empty() {
+			break;
+		}
+		let (name, value) = line.split_once(':')?;
+		headers.push((name.trim().to_string(), value.trim().to_string()));
+	}
+
+	let length = headers
+		.iter()
+		.find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+		.and_then(|(_, v)| v.parse().ok())
+		.unwrap_or(0);
+	let mut body = vec![0; length];
+	reader.read_exact(&mut body).ok()?;
+
+	Some(Request {
+		method,
+		path,
+		headers,
+		body: String::from_utf8(body).ok()?,
+	})
+}
+
+/// An empty directory for one test to write into.
+pub fn temp_dir(name: &str) -> PathBuf {
+	let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).unwrap();
+	dir
+}
+
+pub fn fixture(name: &str) -> String {
+	let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+		.join("tests/fixtures")
+		.join(name);
+	fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
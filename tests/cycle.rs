@@ -0,0 +1,44 @@
+use aoc2023::cycle::{self, Period};
+
+/// 0, 1, 2, 3, 4, then round 2, 3, 4 forever.
+fn step(n: &u32) -> u32 {
+	if *n == 4 {
+		2
+	} else {
+		n + 1
+	}
+}
+
+#[test]
+fn find_and_brent_agree() {
+	let expected = Period {
+		start: 2,
+		length: 3,
+	};
+	let cycle = cycle::find(0, step);
+	assert_eq!(cycle.period, expected);
+	assert_eq!(cycle.states, [0, 1, 2, 3, 4]);
+	assert_eq!(cycle::brent(0, step), expected);
+
+	// a cycle right from the start
+	let period = cycle::brent(0u32, |n| (n + 1) % 7);
+	assert_eq!(
+		period,
+		Period {
+			start: 0,
+			length: 7
+		}
+	);
+}
+
+#[test]
+fn state_after_many_steps() {
+	let cycle = cycle::find(0, step);
+	assert_eq!(*cycle.state_after(1), 1);
+	assert_eq!(*cycle.state_after(5), 2);
+	assert_eq!(
+		*cycle.state_after(1_000_000_000),
+		2 + (1_000_000_000 - 2) % 3
+	);
+	assert_eq!(cycle.period.reduce(7), 4);
+}
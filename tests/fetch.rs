@@ -0,0 +1,77 @@
+mod common;
+
+use std::fs;
+
+use aoc2023::fetch::{self, Client, Outcome, Paths, YEAR};
+use common::MockServer;
+
+const INPUT: &str = "LR\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+
+fn serve_day8() -> MockServer {
+	MockServer::start(|request| {
+		if request.header("Cookie") != Some("session=secret") {
+			return (
+				400,
+				"Puzzle inputs differ by user.  Please log in.".to_string(),
+			);
+		}
+		match request.path.as_str() {
+			p if p == format!("/{YEAR}/day/8") => (200, common::fixture("day8.html")),
+			p if p == format!("/{YEAR}/day/8/input") => (200, INPUT.to_string()),
+			_ => (404, "404 Not Found".to_string()),
+		}
+	})
+}
+
+#[test]
+fn downloads_then_uses_cache() {
+	let server = serve_day8();
+	let client = Client::new(&server.url, "secret\n");
+	let root = common::temp_dir("fetch");
+	let paths = Paths::new(&root, 8);
+
+	let summary = fetch::fetch_day(&client, &paths, 8, false).unwrap();
+	assert_eq!(summary.page, Outcome::Saved);
+	assert_eq!(summary.input, Outcome::Saved);
+	assert_eq!(fs::read_to_string(&paths.input).unwrap(), INPUT);
+	let names: Vec<&str> = summary.examples.iter().map(|s| s.name.as_str()).collect();
+	assert_eq!(names, ["example8", "example8-2", "example8-3"]);
+	for saved in &summary.examples {
+		assert_eq!(saved.outcome, Outcome::Saved);
+		assert_eq!(
+			fs::read_to_string(paths.examples.join(format!("{}.txt", saved.name))).unwrap(),
+			fs::read_to_string(format!("inputs/{}.txt", saved.name)).unwrap()
+		);
+	}
+	assert_eq!(server.requests().len(), 2);
+
+	let summary = fetch::fetch_day(&client, &paths, 8, false).unwrap();
+	assert_eq!(summary.page, Outcome::Cached);
+	assert!(summary
+		.examples
+		.iter()
+		.all(|s| s.outcome == Outcome::Cached));
+	assert_eq!(summary.input, Outcome::Cached);
+	assert_eq!(server.requests().len(), 2);
+
+	let summary = fetch::fetch_day(&client, &paths, 8, true).unwrap();
+	assert_eq!(summary.page, Outcome::Saved);
+	assert_eq!(summary.input, Outcome::Cached);
+	assert_eq!(server.requests().len(), 3);
+
+	let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn reports_http_errors() {
+	let server = serve_day8();
+	let client = Client::new(&server.url, "wrong");
+	let root = common::temp_dir("fetch-unauthorized");
+	let paths = Paths::new(&root, 8);
+
+	let error = fetch::fetch_day(&client, &paths, 8, false).unwrap_err();
+	assert!(error.contains("HTTP 400"), "{error}");
+	assert!(!paths.page.exists());
+
+	let _ = fs::remove_dir_all(&root);
+}
@@ -0,0 +1,54 @@
+use aoc2023::{
+	flood::{self, Connectivity},
+	grid::Grid,
+};
+
+fn maze() -> Grid<bool> {
+	let input = "\
+..#..
+..#..
+##.##
+..#..
+";
+	Grid::parse_with(input, input.lines(), ". or #", |c| match c {
+		'.' => Some(true),
+		'#' => Some(false),
+		_ => None,
+	})
+	.unwrap()
+}
+
+#[test]
+fn fill_respects_connectivity() {
+	let maze = maze();
+	let open = |_, to| maze[to];
+
+	let four = flood::fill(&maze, [(0, 0)], Connectivity::Four, open);
+	assert_eq!(four.size, 4);
+	assert!(four.contains((1, 1)) && !four.contains((2, 2)));
+	assert_eq!(four.distances[(1, 1)], Some(2));
+	assert_eq!(four.max_distance(), Some(2));
+
+	// the middle cell only touches the corners' regions diagonally
+	let eight = flood::fill(&maze, [(0, 0)], Connectivity::Eight, open);
+	assert_eq!(eight.size, 4 + 1 + 4 + 2 + 2);
+	assert_eq!(eight.distances[(4, 3)], Some(4));
+}
+
+#[test]
+fn components_label_and_size_regions() {
+	let maze = maze();
+	let same = |from, to| maze[from] == maze[to];
+
+	let regions = flood::components(&maze, Connectivity::Four, same);
+	// four open corners, the middle, and the walls split into four pieces
+	assert_eq!(regions.sizes.len(), 9);
+	assert_eq!(regions.sizes.iter().sum::<usize>(), 20);
+	assert_eq!(regions.labels[(0, 0)], 0);
+	assert_eq!(regions.labels[(1, 1)], 0);
+	assert_eq!(regions.sizes[0], 4);
+	assert_ne!(regions.labels[(3, 0)], regions.labels[(0, 3)]);
+
+	let regions = flood::components(&maze, Connectivity::Eight, same);
+	assert_eq!(regions.sizes, [13, 7]);
+}
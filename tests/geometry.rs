@@ -0,0 +1,44 @@
+use aoc2023::geometry::{
+	Direction::{self, *},
+	Point,
+};
+
+#[test]
+fn turns() {
+	assert_eq!(Up.turn_right(), Right);
+	assert_eq!(Up.turn_left(), Left);
+	assert_eq!(Left.turn_right(), Up);
+	assert_eq!(Down.reverse(), Up);
+	assert_eq!(Right.turns(), [Up, Down]);
+	for direction in Direction::ALL {
+		assert_eq!(direction.turn_left().turn_right(), direction);
+		assert_eq!(
+			direction.offset::<i64>(),
+			-direction.reverse().offset::<i64>()
+		);
+	}
+}
+
+#[test]
+fn masks_and_parsing() {
+	let mask = Up.bit() | Left.bit();
+	assert_eq!(Direction::from_mask(mask).collect::<Vec<_>>(), [Up, Left]);
+	assert_eq!(Direction::from_mask(0).count(), 0);
+
+	assert_eq!(Direction::from_letter('R'), Some(Right));
+	assert_eq!(Direction::from_letter('x'), None);
+	assert_eq!(Direction::from_digit('3'), Some(Up));
+	assert_eq!(Direction::from_digit('4'), None);
+}
+
+#[test]
+fn points() {
+	let p = Point::new(2i64, -1);
+	assert_eq!(p + Right.offset() * 3, Point::new(5, -1));
+	assert_eq!(p.manhattan(Point::new(0, 2)), 5);
+
+	let corner = Point::new(0usize, 0);
+	assert_eq!(corner.step(Up, 3, 3), None);
+	assert_eq!(corner.step(Down, 3, 3), Some(Point::new(0, 1)));
+	assert_eq!(Point::new(2usize, 0).step(Right, 3, 3), None);
+}
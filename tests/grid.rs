@@ -0,0 +1,71 @@
+use aoc2023::grid::Grid;
+
+fn sample() -> Grid<char> {
+	let input = "abc\ndef\n";
+	Grid::parse_with(input, input.lines(), "a letter", Some).unwrap()
+}
+
+#[test]
+fn parses_and_indexes() {
+	let grid = sample();
+	assert_eq!((grid.width(), grid.height()), (3, 2));
+	assert_eq!(grid[(2, 0)], 'c');
+	assert_eq!(grid.get(0, 1), Some(&'d'));
+	assert_eq!(grid.get(-1, 0), None);
+	assert_eq!(grid.get(3, 0), None);
+	assert_eq!(grid.get(0, 2), None);
+	assert_eq!(grid.row(1), ['d', 'e', 'f']);
+	assert_eq!(grid.column(1).collect::<String>(), "be");
+	assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+	assert_eq!(grid.to_string(), "abc\ndef\n");
+
+	let input = "abc\nde\n";
+	let error = Grid::<char>::parse_with(input, input.lines(), "a letter", Some).unwrap_err();
+	assert_eq!((error.line, error.column), (2, 1));
+	assert_eq!(error.expected, "a row of 3 characters");
+
+	// grids can't be empty, so solvers can rely on there being a last row and column
+	let error = Grid::<char>::parse_with("", "".lines(), "a letter", Some).unwrap_err();
+	assert_eq!(
+		(error.line, error.column, error.expected.as_str()),
+		(1, 1, "a grid")
+	);
+	let input = "\nabc\n";
+	let error = Grid::<char>::parse_with(input, input.lines(), "a letter", Some).unwrap_err();
+	assert_eq!((error.line, error.column), (1, 1));
+	assert_eq!(error.expected, "a row of a letter");
+}
+
+#[test]
+fn neighbors() {
+	let grid = sample();
+	assert_eq!(
+		grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+		[(1, 0), (0, 1)]
+	);
+	assert_eq!(
+		grid.neighbors4((1, 1)).collect::<Vec<_>>(),
+		[(1, 0), (0, 1), (2, 1)]
+	);
+	assert_eq!(
+		grid.neighbors8((1, 0)).collect::<Vec<_>>(),
+		[(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+	);
+	assert_eq!(grid.step((2, 1), (1, 0)), None);
+	assert_eq!(grid.step((2, 1), (-2, -1)), Some((0, 0)));
+}
+
+#[test]
+fn transforms() {
+	let grid = sample();
+	assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+	assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+	assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+	assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+	assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+	assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+	assert_eq!(
+		grid.map(|c| c.to_ascii_uppercase()).render(|&c| c),
+		"ABC\nDEF\n"
+	);
+}
@@ -0,0 +1,52 @@
+use aoc2023::{
+	input::{self, Change},
+	Day, Part,
+};
+
+#[test]
+fn clean_input_is_unchanged() {
+	let normalized = input::normalize("a\n\nb\n");
+	assert_eq!(normalized.text, "a\n\nb\n");
+	assert!(normalized.changes.is_empty());
+	assert_eq!(normalized.summary(), None);
+	assert_eq!(input::normalize("").text, "");
+}
+
+#[test]
+fn fixes_everything() {
+	let normalized = input::normalize("\u{feff}a  \r\n\r\nb\t\r\nc\r\n\r\n\r\n");
+	assert_eq!(normalized.text, "a\n\nb\nc\n");
+	assert_eq!(
+		normalized.changes,
+		[
+			Change::RemovedBom,
+			Change::ConvertedLineEndings,
+			Change::TrimmedLines(2),
+			Change::RemovedBlankLines(2),
+		]
+	);
+	assert_eq!(
+		normalized.summary().unwrap(),
+		"removed a byte order mark, converted line endings to LF, removed trailing whitespace \
+		 from 2 lines, removed 2 blank lines from the end"
+	);
+
+	let normalized = input::normalize("a\rb");
+	assert_eq!(normalized.text, "a\nb\n");
+	assert_eq!(
+		normalized.changes,
+		[Change::ConvertedLineEndings, Change::AddedFinalNewline]
+	);
+}
+
+/// Days that split on blank lines or match whole lines see the same input either way.
+#[test]
+fn days_agree_after_normalizing() {
+	for (number, path) in [(5, "inputs/example5.txt"), (13, "inputs/example13.txt")] {
+		let clean = std::fs::read_to_string(path).unwrap();
+		let messy = format!("\u{feff}{}  \r\n", clean.replace('\n', " \r\n"));
+		let day = Day::find(number).unwrap();
+		let solve = |input: &str| day.solve(input, &[Part::One]).unwrap()[0].answer.clone();
+		assert_eq!(solve(&input::normalize(&messy).text), solve(&clean));
+	}
+}
@@ -0,0 +1,66 @@
+use aoc2023::interval::{Interval, IntervalBox, IntervalSet};
+
+#[test]
+fn intervals() {
+	let a = Interval::new(0, 10);
+	assert_eq!(a.len(), 10);
+	assert!(a.contains(9) && !a.contains(10));
+	assert_eq!(a.intersect(Interval::new(5, 15)), Interval::new(5, 10));
+	assert!(a.intersect(Interval::new(10, 15)).is_empty());
+	assert_eq!(a.intersect(Interval::new(12, 15)).len(), 0);
+	assert_eq!(a.split_at(3), (Interval::new(0, 3), Interval::new(3, 10)));
+	assert!(a.split_at(-5).0.is_empty());
+	assert_eq!(
+		a.difference(Interval::new(3, 5)),
+		[Interval::new(0, 3), Interval::new(5, 10)]
+	);
+	assert_eq!(a.shift(-2), Interval::new(-2, 8));
+	assert_eq!(Interval::inclusive(1, 4000).len(), 4000);
+}
+
+#[test]
+fn sets_merge_and_subtract() {
+	let mut set: IntervalSet = [
+		Interval::new(5, 8),
+		Interval::new(0, 3),
+		Interval::new(3, 4),
+	]
+	.into_iter()
+	.collect();
+	assert_eq!(
+		set.iter().collect::<Vec<_>>(),
+		[Interval::new(0, 4), Interval::new(5, 8)]
+	);
+	assert_eq!(set.len(), 7);
+	assert!(set.contains(6) && !set.contains(4));
+
+	set.insert(Interval::new(2, 6));
+	assert_eq!(set.iter().collect::<Vec<_>>(), [Interval::new(0, 8)]);
+
+	set.remove(Interval::new(2, 3));
+	let other: IntervalSet = [Interval::new(6, 20)].into_iter().collect();
+	assert_eq!(
+		set.difference(&other).iter().collect::<Vec<_>>(),
+		[Interval::new(0, 2), Interval::new(3, 6)]
+	);
+	assert_eq!(
+		set.intersect(&other).iter().collect::<Vec<_>>(),
+		[Interval::new(6, 8)]
+	);
+	assert_eq!(set.union(&other).len(), 19);
+	assert_eq!((set.min(), set.max()), (Some(0), Some(7)));
+	assert_eq!(set.shift(10).min(), Some(10));
+	assert!(IntervalSet::new().is_empty());
+}
+
+#[test]
+fn boxes() {
+	let cube = IntervalBox::new([Interval::new(0, 4); 3]);
+	assert_eq!(cube.volume(), 64);
+	assert!(cube.contains([0, 3, 2]) && !cube.contains([0, 4, 2]));
+
+	let (below, above) = cube.split_at(1, 1);
+	assert_eq!((below.volume(), above.volume()), (16, 48));
+	assert_eq!(below.intersect(&above).volume(), 0);
+	assert!(cube.with_axis(2, Interval::EMPTY).is_empty());
+}
@@ -0,0 +1,31 @@
+use aoc2023::iter::{IterExt, SliceExt};
+
+#[test]
+fn iterators() {
+	let mut words = "a b c d e".split(' ');
+	assert_eq!(words.next_array(), Some(["a", "b"]));
+	assert_eq!(words.next_array::<4>(), None);
+	assert_eq!(words.next(), None);
+
+	assert_eq!("xy".chars().collect_array(), Some(['x', 'y']));
+	assert_eq!("xyz".chars().collect_array::<2>(), None);
+	assert_eq!("x".chars().collect_array::<2>(), None);
+
+	let pairs: Vec<[u32; 2]> = (1..=5).arrays().collect();
+	assert_eq!(pairs, [[1, 2], [3, 4]]);
+}
+
+#[test]
+fn slices() {
+	let seeds = [79, 14, 55, 13, 1];
+	let ranges: Vec<(i32, i32)> = SliceExt::array_chunks(&seeds[..])
+		.map(|&[s, l]| (s, s + l))
+		.collect();
+	assert_eq!(ranges, [(79, 93), (55, 68)]);
+
+	let differences: Vec<i32> = SliceExt::array_windows(&seeds[..])
+		.map(|&[a, b]| b - a)
+		.collect();
+	assert_eq!(differences, [-65, 41, -42, -12]);
+	assert_eq!(SliceExt::array_windows::<6>(&seeds[..]).count(), 0);
+}
@@ -0,0 +1,50 @@
+use aoc2023::math;
+
+#[test]
+fn gcd_and_lcm() {
+	assert_eq!(math::gcd(12, 18), 6);
+	assert_eq!(math::gcd(0, 5), 5);
+	assert_eq!(math::gcd(0, 0), 0);
+	assert_eq!(math::lcm(4, 6), Some(12));
+	assert_eq!(math::lcm(0, 6), Some(0));
+	assert_eq!(math::lcm_all([2, 3, 4]), Some(12));
+	assert_eq!(math::lcm_all([]), Some(1));
+
+	// the product of these overflows, but their lcm doesn't
+	let big = 1 << 62;
+	assert_eq!(math::lcm(big, big / 2), Some(big));
+	assert_eq!(math::lcm(u64::MAX, u64::MAX - 1), None);
+}
+
+#[test]
+fn extended_gcd_and_inverse() {
+	for (a, b) in [(240, 46), (-240, 46), (7, 0), (0, -7), (13, 17)] {
+		let (g, x, y) = math::extended_gcd(a, b).unwrap();
+		assert!(g >= 0);
+		assert_eq!(a * x + b * y, g, "{a}, {b}");
+	}
+	assert_eq!(math::extended_gcd(240, 46).unwrap().0, 2);
+	// the gcd would be 2^63
+	assert_eq!(math::extended_gcd(i64::MIN, 0), None);
+	assert_eq!(math::extended_gcd(i64::MIN, 3).unwrap().0, 1);
+
+	assert_eq!(math::mod_inverse(3, 11), Some(4));
+	assert_eq!(math::mod_inverse(-3, 11), Some(7));
+	assert_eq!(math::mod_inverse(6, 9), None);
+}
+
+#[test]
+fn chinese_remainders() {
+	assert_eq!(math::crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+	// moduli sharing a factor, consistent and not
+	assert_eq!(math::crt([(3, 4), (1, 6)]), Some((7, 12)));
+	assert_eq!(math::crt([(0, 4), (1, 6)]), None);
+	assert_eq!(math::crt([(-1, 5)]), Some((4, 5)));
+	assert_eq!(math::crt([]), Some((0, 1)));
+
+	let primes = [1_000_000_007, 998_244_353, 1_000_000_009];
+	assert_eq!(math::crt(primes.map(|p| (1, p))), None);
+	let (x, m) = math::crt([(5, primes[0]), (7, primes[1])]).unwrap();
+	assert_eq!(m, primes[0] * primes[1]);
+	assert_eq!((x % primes[0], x % primes[1]), (5, 7));
+}
@@ -0,0 +1,39 @@
+use aoc2023::memo::{Memo, Stats};
+
+fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+	if n < 2 {
+		return n;
+	}
+	memo.get_or_compute(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+}
+
+#[test]
+fn recursion() {
+	let mut memo = Memo::new();
+	assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+	// each of 2..=90 is computed once, and every one but the last two is looked up again
+	assert_eq!(
+		memo.stats(),
+		Stats {
+			hits: 87,
+			misses: 89
+		}
+	);
+	assert_eq!(memo.len(), 89);
+
+	assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+	assert_eq!(memo.stats().hits, 88);
+}
+
+#[test]
+fn clear() {
+	let mut memo: Memo<(usize, usize), usize> = Memo::new();
+	assert_eq!(memo.get_or_compute((1, 2), |_| 3), 3);
+	assert_eq!(memo.get_or_compute((1, 2), |_| unreachable!()), 3);
+	assert_eq!(memo.stats().hit_rate(), 0.5);
+
+	memo.clear();
+	assert!(memo.is_empty());
+	assert_eq!(memo.stats(), Stats::default());
+	assert_eq!(memo.get_or_compute((1, 2), |_| 4), 4);
+}
@@ -0,0 +1,96 @@
+use std::fs;
+
+use aoc2023::{
+	params::{self, Param, Params},
+	Answer, Day, Part,
+};
+
+#[test]
+fn overrides() {
+	const DECLARED: &[Param] = &[
+		Param::new("red", 12, "red cubes"),
+		Param::new("blue", 14, "blue cubes"),
+	];
+	let params =
+		Params::with_overrides(DECLARED, &[params::parse_override("blue=-3").unwrap()]).unwrap();
+	assert_eq!(params.get::<i64>("red"), 12);
+	assert_eq!(params.get::<i32>("blue"), -3);
+
+	let error = Params::with_overrides(DECLARED, &[("green".to_string(), 1)]).unwrap_err();
+	assert_eq!(
+		error,
+		"unknown parameter \"green\"; expected one of red, blue"
+	);
+
+	const RANGED: &[Param] = &[Param::new("cycles", 3, "spin cycles").range(0, 10)];
+	let error = Params::with_overrides(RANGED, &[("cycles".to_string(), -5)]).unwrap_err();
+	assert_eq!(
+		error,
+		"invalid value -5 for parameter cycles, expected 0..=10"
+	);
+	assert!(Params::with_overrides(RANGED, &[("cycles".to_string(), 10)]).is_ok());
+
+	assert!(params::parse_override("red").is_err());
+	assert!(params::parse_override("red=many").is_err());
+}
+
+#[test]
+fn days_use_overrides() {
+	let day = Day::find(11).unwrap();
+	assert!(day.params.iter().any(|p| p.name == "expansion"));
+	let input = fs::read_to_string("inputs/example11.txt").unwrap();
+
+	// the puzzle's examples for part 2 use much smaller expansions than the real one
+	for (expansion, expected) in [(10, 1030), (100, 8410)] {
+		let params = day
+			.resolve_params(&[("expansion".to_string(), expansion)])
+			.unwrap();
+		let reports = day.solve_with(&input, &[Part::Two], &params).unwrap();
+		assert_eq!(reports[0].answer, Answer::Number(expected));
+	}
+}
+
+#[test]
+fn days_reject_invalid_params() {
+	let resolve = |day: u8, overrides: &[(&str, i64)]| {
+		let overrides: Vec<(String, i64)> = overrides
+			.iter()
+			.map(|&(name, value)| (name.to_string(), value))
+			.collect();
+		Day::find(day).unwrap().resolve_params(&overrides)
+	};
+
+	// values the solvers would crash on or give meaningless answers for
+	assert!(resolve(2, &[("red", -1)]).is_err());
+	assert!(resolve(11, &[("expansion", 0)]).is_err());
+	assert!(resolve(14, &[("cycles", -5)]).is_err());
+	assert!(resolve(17, &[("max_straight", 0)]).is_err());
+	assert!(resolve(17, &[("ultra_min_straight", 20)]).is_err());
+	assert!(resolve(19, &[("min_rating", 10), ("max_rating", 9)]).is_err());
+
+	assert!(resolve(17, &[("ultra_min_straight", 10)]).is_ok());
+	assert!(resolve(19, &[("min_rating", 10), ("max_rating", 10)]).is_ok());
+}
+
+#[test]
+fn day17_starting_at_the_factory() {
+	// the crucible is already there, so it doesn't lose any heat
+	let day = Day::find(17).unwrap();
+	let reports = day.solve("1\n", day.parts()).unwrap();
+	assert!(reports.iter().all(|r| r.answer == Answer::Number(0)));
+}
+
+#[test]
+fn day17_rejects_limits_the_grid_cant_fit() {
+	// in range, but longer than the grid is wide
+	let day = Day::find(17).unwrap();
+	let input = fs::read_to_string("inputs/example17.txt").unwrap();
+	let params = day
+		.resolve_params(&[
+			("ultra_min_straight".to_string(), 50),
+			("ultra_max_straight".to_string(), 60),
+		])
+		.unwrap();
+	let error = day.solve_with(&input, day.parts(), &params).unwrap_err();
+	assert_eq!((error.line, error.column), (13, 13));
+}
@@ -0,0 +1,129 @@
+use aoc2023::{
+	parse::{self, ParseError},
+	Day, Part,
+};
+
+#[test]
+fn positions() {
+	let input = "abc\nd\u{e9}f ghi\n";
+	let error = ParseError::at(input, &input[9..12], "something else");
+	assert_eq!((error.line, error.column), (2, 5));
+	assert_eq!(error.text, "ghi");
+	assert_eq!(
+		error.to_string(),
+		"line 2, column 5: expected something else, found \"ghi\""
+	);
+
+	let end = ParseError::end(input, "more");
+	assert_eq!((end.line, end.column), (3, 1));
+	assert_eq!(
+		end.to_string(),
+		"line 3, column 1: expected more, found nothing"
+	);
+}
+
+#[test]
+fn report() {
+	let input = "Game 1: 3 blue\nGame 2: x blue\n";
+	let error = parse::number::<u32>(input, &input[23..24]).unwrap_err();
+	assert_eq!(
+		error.report(input),
+		"\
+line 2, column 9: expected a number, found \"x\"
+  |
+2 | Game 2: x blue
+  |         ^"
+	);
+}
+
+#[test]
+fn days_reject_bad_input() {
+	let cases = [
+		(2, "Game 1: 3 blue, 4 purple\n", (1, 19)),
+		(7, "32T3K 765\nT55J 684\n", (2, 1)),
+		(11, "..#\n.x.\n", (2, 2)),
+		(18, "R 6 (#70c710)\nD 5 (#0dc574)\n", (2, 12)),
+	];
+	for (number, input, position) in cases {
+		let day = Day::find(number).unwrap();
+		let error = match day.solve(input, &[Part::One]) {
+			Ok(_) => panic!("day {number} accepted {input:?}"),
+			Err(error) => error,
+		};
+		assert_eq!(
+			(error.line, error.column),
+			position,
+			"day {number}: {error}"
+		);
+	}
+}
+
+#[test]
+fn sections_and_lists() {
+	let input = "\nseeds: 79 14\n\n\nab\ncd\n\n";
+	let sections: Vec<&str> = parse::sections(input).collect();
+	assert_eq!(sections, ["seeds: 79 14", "ab\ncd"]);
+
+	let seeds = parse::strip_prefix(input, sections[0], "seeds: ").unwrap();
+	assert_eq!(parse::numbers::<u32>(input, seeds).unwrap(), [79, 14]);
+
+	let error = parse::section_body(input, sections[1], "ab:").unwrap_err();
+	assert_eq!(
+		(error.line, error.column, error.text.as_str()),
+		(5, 1, "ab")
+	);
+
+	let input = "x=787,m=2655";
+	let pairs = parse::key_values(input, input, ",", "=").unwrap();
+	assert_eq!(pairs, [("x", "787"), ("m", "2655")]);
+	let error = parse::list(input, ",", |n| parse::number::<u32>(input, n)).unwrap_err();
+	assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn extract_numbers() {
+	let input = "p=-3,+4 v=12..-07 x-5";
+	let numbers: Vec<i64> = parse::extract_numbers(input, input).unwrap();
+	assert_eq!(numbers, [-3, 4, 12, -7, 5]);
+
+	// a dash after a letter or digit is a hyphen, not a sign
+	let numbers: Vec<i64> = parse::extract_numbers("x-5", "x-5").unwrap();
+	assert_eq!(numbers, [5]);
+	let numbers: Vec<i64> = parse::extract_numbers("1-2", "1-2").unwrap();
+	assert_eq!(numbers, [1, 2]);
+	let numbers: Vec<i64> = parse::extract_numbers("-1--2", "-1--2").unwrap();
+	assert_eq!(numbers, [-1, -2]);
+
+	let error = parse::extract_numbers::<u8>(input, &input[8..]).unwrap_err();
+	assert_eq!((error.column, error.text.as_str()), (15, "-07"));
+}
+
+#[test]
+fn cursor() {
+	let input = "AAA = (BBB, -12)\nCC";
+	let line = input.lines().next().unwrap();
+	let mut cursor = parse::Cursor::new(input, line);
+	assert_eq!(cursor.take(3, "a name").unwrap(), "AAA");
+	cursor.expect(" = (").unwrap();
+	assert_eq!(cursor.until(", ").unwrap(), "BBB");
+	assert_eq!(cursor.number::<i32>().unwrap(), -12);
+	let error = cursor.finish().unwrap_err();
+	assert_eq!((error.column, error.text.as_str()), (16, ")"));
+
+	let mut cursor = parse::Cursor::new(input, &input[17..]);
+	let error = cursor.take(3, "a name").unwrap_err();
+	assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, ""));
+	let error = cursor.number::<u32>().unwrap_err();
+	assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "C"));
+}
+
+#[test]
+fn grid_days_reject_empty_input() {
+	for number in [3, 10, 11, 14, 16, 17] {
+		let day = Day::find(number).unwrap();
+		assert!(
+			day.solve("", day.parts()).is_err(),
+			"day {number} accepted empty input"
+		);
+	}
+}
@@ -0,0 +1,53 @@
+use aoc2023::pathfinding;
+
+/// A graph where `a -> c` directly costs more than going through `b`.
+fn edges(node: &char) -> Vec<(char, u32)> {
+	match node {
+		'a' => vec![('b', 1), ('c', 5)],
+		'b' => vec![('c', 1), ('d', 7)],
+		'c' => vec![('d', 1)],
+		_ => vec![],
+	}
+}
+
+#[test]
+fn bfs_counts_steps() {
+	let path = pathfinding::bfs(
+		[0i32],
+		|&n| [n + 1, n * 2].into_iter().filter(|&m| m <= 10),
+		|&n| n == 10,
+	)
+	.unwrap();
+	assert_eq!(path.cost, 5);
+	assert_eq!(path.path, [0, 1, 2, 4, 5, 10]);
+	assert!(path.stats.expanded > 0);
+	assert!(path.stats.discovered >= path.stats.expanded);
+
+	let unreachable = pathfinding::bfs(
+		[0i32],
+		|&n| [n + 2].into_iter().filter(|&m| m < 10),
+		|&n| n == 5,
+	);
+	assert!(unreachable.is_none());
+}
+
+#[test]
+fn dijkstra_finds_the_cheapest_path() {
+	let path = pathfinding::dijkstra(['a'], edges, |&n| n == 'd').unwrap();
+	assert_eq!(path.cost, 3);
+	assert_eq!(path.path, ['a', 'b', 'c', 'd']);
+
+	assert!(pathfinding::dijkstra(['d'], edges, |&n| n == 'a').is_none());
+}
+
+#[test]
+fn astar_expands_less_with_a_heuristic() {
+	// walk along a line from 0 to 20, where every step costs 1
+	let successors = |&n: &i32| [(n - 1, 1), (n + 1, 1)];
+	let blind = pathfinding::dijkstra([0], successors, |&n| n == 20).unwrap();
+	let guided = pathfinding::astar([0], successors, |&n| (20 - n).abs(), |&n| n == 20).unwrap();
+	assert_eq!(blind.cost, 20);
+	assert_eq!(guided.cost, 20);
+	assert_eq!(guided.path, (0..=20).collect::<Vec<_>>());
+	assert!(guided.stats.expanded < blind.stats.expanded);
+}
@@ -0,0 +1,32 @@
+use aoc2023::{
+	geometry::{Direction::*, Point},
+	polygon::Polygon,
+};
+
+#[test]
+fn square() {
+	// a 4x4 square of tiles traced through their centres
+	let square = Polygon::from_moves(
+		Point::new(0, 0),
+		[(Right, 3), (Down, 3), (Left, 3), (Up, 3)],
+	);
+	assert_eq!(square.vertices().len(), 4);
+	assert_eq!(square.double_signed_area().abs(), 18);
+	assert_eq!(square.area(), 9);
+	assert_eq!(square.boundary_points(), 12);
+	assert_eq!(square.interior_points(), 4);
+	assert_eq!(square.tiles_covered(), 16);
+}
+
+#[test]
+fn orientation_and_diagonals() {
+	let vertices = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 2)];
+	let forwards = Polygon::from_vertices(vertices);
+	let backwards = Polygon::from_vertices(vertices.into_iter().rev());
+	assert_eq!(forwards.double_signed_area(), 8);
+	assert_eq!(backwards.double_signed_area(), -8);
+
+	// the hypotenuse from (4, 0) to (0, 2) passes through (2, 1)
+	assert_eq!(forwards.boundary_points(), 8);
+	assert_eq!(forwards.interior_points(), 1);
+}
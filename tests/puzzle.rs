@@ -0,0 +1,95 @@
+mod common;
+
+use std::fs;
+
+use aoc2023::{
+	answers::{self, Expected},
+	fetch,
+	puzzle::{self, Example},
+	Answer, Part,
+};
+
+fn example(part: Part, input: &str, answer: Option<&str>) -> Example {
+	Example {
+		part,
+		input: input.to_string(),
+		answer: answer.map(str::to_string),
+	}
+}
+
+#[test]
+fn several_examples() {
+	let examples = puzzle::examples(&common::fixture("day8.html"));
+	let example8 = |name: &str| fs::read_to_string(format!("inputs/{name}.txt")).unwrap();
+	assert_eq!(
+		examples,
+		[
+			// the 6 is in the paragraph introducing the second example
+			example(Part::One, &example8("example8"), Some("2")),
+			example(Part::One, &example8("example8-2"), Some("6")),
+			example(Part::Two, &example8("example8-3"), Some("6")),
+		]
+	);
+}
+
+#[test]
+fn blocks_without_answers() {
+	let examples = puzzle::examples(&common::fixture("day6.html"));
+	let races = "Time:      7  15   30\nDistance:  9  40  200\n";
+	assert_eq!(
+		examples,
+		[
+			example(Part::One, races, Some("288")),
+			example(Part::Two, races, None),
+			example(
+				Part::Two,
+				"Time:      71530\nDistance:  940200\n",
+				Some("71503")
+			),
+		]
+	);
+}
+
+#[test]
+fn unescapes_blocks() {
+	let html = "<article class=\"day-desc\"><pre><code>a &lt;<em>b</em>&gt; &amp;\n</code></pre>\
+	            <p>answer <code><em>&lt;b&gt;</em></code></p></article>";
+	assert_eq!(
+		puzzle::examples(html),
+		[example(Part::One, "a <b> &\n", Some("<b>"))]
+	);
+}
+
+#[test]
+fn saves_and_proposes() {
+	let dir = common::temp_dir("examples");
+	let races = "Time:      7  15   30\nDistance:  9  40  200\n";
+	// written by hand under a name that doesn't follow page order
+	fs::write(dir.join("example6-5.txt"), races).unwrap();
+
+	let examples = puzzle::examples(&common::fixture("day6.html"));
+	let saved = fetch::save_examples(&dir, 6, &examples).unwrap();
+	let names: Vec<&str> = saved.iter().map(|s| s.name.as_str()).collect();
+	assert_eq!(names, ["example6-5", "example6-5", "example6"]);
+	assert_eq!(saved[2].outcome, fetch::Outcome::Saved);
+
+	let registry = dir.join("answers.toml");
+	fs::write(&registry, "[example6-5]\npart1 = 288\n").unwrap();
+	let proposals: Vec<Expected> = saved.iter().filter_map(|s| s.proposal(6)).collect();
+	let added = answers::add(&registry, &proposals, "proposed").unwrap();
+	assert_eq!(
+		added,
+		[Expected {
+			input: "example6".to_string(),
+			day: 6,
+			part: Part::Two,
+			answer: Answer::Number(71503),
+		}]
+	);
+	assert_eq!(answers::load(&registry).unwrap().len(), 2);
+	assert!(answers::add(&registry, &proposals, "proposed")
+		.unwrap()
+		.is_empty());
+
+	let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use std::fs;
+
+use aoc2023::{answers, scaffold, Answer, Part};
+
+const MOD_RS: &str = "\
+pub mod day02;
+pub mod day19;
+
+use crate::Day;
+
+pub const ALL: &[Day] = &[
+	Day::new::<day02::Day02>(2),
+	Day::new::<day19::Day19>(19),
+];
+";
+
+#[test]
+fn registers_in_order() {
+	assert_eq!(
+		scaffold::register(MOD_RS, 7).unwrap(),
+		"\
+pub mod day02;
+pub mod day07;
+pub mod day19;
+
+use crate::Day;
+
+pub const ALL: &[Day] = &[
+	Day::new::<day02::Day02>(2),
+	Day::new::<day07::Day07>(7),
+	Day::new::<day19::Day19>(19),
+];
+"
+	);
+	let first = scaffold::register(MOD_RS, 1).unwrap();
+	assert!(first.starts_with("pub mod day01;\npub mod day02;\n"));
+	let last = scaffold::register(MOD_RS, 25).unwrap();
+	assert!(last.contains("\tDay::new::<day19::Day19>(19),\n\tDay::new::<day25::Day25>(25),\n];"));
+	assert!(scaffold::register(MOD_RS, 19).is_err());
+}
+
+#[test]
+fn creates_a_day_once() {
+	let root = common::temp_dir("scaffold");
+	fs::create_dir_all(root.join("src/days")).unwrap();
+	fs::write(root.join("src/days/mod.rs"), MOD_RS).unwrap();
+	fs::write(root.join("answers.toml"), "[example2]\npart1 = 8\n").unwrap();
+
+	let changed = scaffold::new_day(&root, 20).unwrap();
+	assert_eq!(changed.len(), 3);
+	let module = fs::read_to_string(root.join("src/days/day20.rs")).unwrap();
+	assert!(module.contains("impl Solution for Day20 {"));
+	let mod_rs = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+	assert!(mod_rs.contains("pub mod day20;"));
+	assert!(mod_rs.contains("Day::new::<day20::Day20>(20),"));
+
+	let registry = answers::load(&root.join("answers.toml")).unwrap();
+	let added = registry.last().unwrap();
+	assert_eq!(added.input, "example20");
+	assert_eq!(added.part, Part::One);
+	assert_eq!(added.answer, Answer::Text("TODO".to_string()));
+
+	// nothing is touched the second time
+	fs::write(root.join("src/days/day20.rs"), "// work in progress\n").unwrap();
+	assert!(scaffold::new_day(&root, 20).is_err());
+	assert_eq!(
+		fs::read_to_string(root.join("src/days/day20.rs")).unwrap(),
+		"// work in progress\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+		mod_rs
+	);
+	assert_eq!(answers::load(&root.join("answers.toml")).unwrap().len(), 2);
+
+	let _ = fs::remove_dir_all(&root);
+}
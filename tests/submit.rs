@@ -0,0 +1,120 @@
+mod common;
+
+use std::{
+	fs,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use aoc2023::{
+	fetch::{Client, YEAR},
+	submit::{self, Attempt, Verdict},
+	Answer, Part,
+};
+use common::MockServer;
+
+const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+const ALREADY_SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+const CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. <a href=\"/2023/day/1#part2\">[Continue to Part Two]</a></p></article>";
+
+#[test]
+fn responses() {
+	let cases = [
+		(TOO_HIGH, Verdict::TooHigh, Some(60)),
+		(TOO_LOW, Verdict::TooLow, Some(300)),
+		(WRONG, Verdict::Wrong, Some(60)),
+		(TOO_SOON, Verdict::TooSoon, Some(83)),
+		(ALREADY_SOLVED, Verdict::AlreadySolved, None),
+		(CORRECT, Verdict::Correct, None),
+		("<p>something else</p>", Verdict::Unknown, None),
+	];
+	for (html, verdict, wait) in cases {
+		let response = submit::parse_response(html);
+		assert_eq!(response.verdict, verdict, "{html}");
+		assert_eq!(response.wait, wait.map(Duration::from_secs), "{html}");
+	}
+	assert!(submit::parse_response(CORRECT)
+		.message
+		.starts_with("That's the right answer! You are one gold star closer"));
+}
+
+fn attempt(part: Part, answer: &str, verdict: Verdict, retry_at: u64) -> Attempt {
+	Attempt {
+		timestamp: 1000,
+		day: 1,
+		part,
+		answer: answer.to_string(),
+		verdict,
+		retry_at,
+	}
+}
+
+#[test]
+fn refuses_known_answers() {
+	let history = [
+		attempt(Part::One, "500", Verdict::TooHigh, 1060),
+		attempt(Part::One, "100", Verdict::TooLow, 1060),
+		attempt(Part::One, "300", Verdict::Wrong, 1060),
+		attempt(Part::Two, "abc", Verdict::Correct, 0),
+	];
+	let check = |part, answer: Answer, now| submit::check(&history, 1, part, &answer, now);
+
+	assert!(check(Part::One, 200.into(), 1100).is_ok());
+	assert!(check(Part::One, 200.into(), 1059)
+		.unwrap_err()
+		.contains("wait"));
+	assert!(check(Part::One, 500.into(), 1100)
+		.unwrap_err()
+		.contains("already submitted"));
+	assert!(check(Part::One, 800.into(), 1100)
+		.unwrap_err()
+		.contains("too high"));
+	assert!(check(Part::One, 50.into(), 1100)
+		.unwrap_err()
+		.contains("too low"));
+	assert!(check(Part::One, 300.into(), 1100)
+		.unwrap_err()
+		.contains("already submitted"));
+	assert!(check(Part::Two, "xyz".into(), 1100)
+		.unwrap_err()
+		.contains("already solved"));
+	// another day's history doesn't matter
+	assert!(submit::check(&history, 2, Part::One, &500.into(), 0).is_ok());
+}
+
+#[test]
+fn submits_and_records() {
+	let responses = Arc::new(Mutex::new(vec![CORRECT, TOO_LOW]));
+	let served = responses.clone();
+	let server =
+		MockServer::start(move |_| (200, served.lock().unwrap().pop().unwrap().to_string()));
+	let client = Client::new(&server.url, "secret");
+	let dir = common::temp_dir("submit");
+	let history = dir.join("submissions.tsv");
+
+	let response = submit::submit(&client, &history, 1, Part::One, &Answer::Number(42)).unwrap();
+	assert_eq!(response.verdict, Verdict::TooLow);
+
+	let requests = server.requests();
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].method, "POST");
+	assert_eq!(requests[0].path, format!("/{YEAR}/day/1/answer"));
+	assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+	assert_eq!(requests[0].body, "level=1&answer=42");
+
+	let recorded = submit::load_history(&history);
+	assert_eq!(recorded.len(), 1);
+	assert_eq!(recorded[0].answer, "42");
+	assert_eq!(recorded[0].verdict, Verdict::TooLow);
+	assert_eq!(recorded[0].retry_at, recorded[0].timestamp + 300);
+
+	// still waiting, so nothing is sent
+	let error = submit::submit(&client, &history, 1, Part::One, &Answer::Number(50)).unwrap_err();
+	assert!(error.contains("wait"), "{error}");
+	assert_eq!(server.requests().len(), 1);
+
+	let _ = fs::remove_dir_all(&dir);
+}